@@ -0,0 +1,79 @@
+//! Criterion benchmarks for the normalization hot paths
+//!
+//! Run with `cargo bench`. Criterion warms up, samples each case and compares it with the
+//! results of the previous run, so a regression shows up as a change in the report.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use geo::{LineString, MultiPolygon, Polygon, Rect};
+use geo_normalized2::Normalized;
+use std::hint::black_box;
+
+/// A clockwise, so already normalized, ring approximating a circle
+fn circle(cx: f64, cy: f64, radius: f64, points: u32) -> LineString<f64> {
+    (0..=points)
+        .map(|i| {
+            let angle = -std::f64::consts::TAU * f64::from(i % points) / f64::from(points);
+            (cx + radius * angle.cos(), cy + radius * angle.sin())
+        })
+        .collect()
+}
+
+fn reversed(ring: &LineString<f64>) -> LineString<f64> {
+    ring.0.iter().rev().copied().collect()
+}
+
+fn bench<G: Normalized<f64> + Clone>(c: &mut Criterion, name: &str, geom: &G) {
+    c.bench_function(name, |b| b.iter(|| black_box(geom).normalized()));
+}
+
+fn normalize(c: &mut Criterion) {
+    let valid = Polygon::new(circle(0., 0., 100., 10_000), vec![]);
+    bench(c, "valid polygon, 10k points", &valid);
+
+    let inverted = Polygon::new(reversed(valid.exterior()), vec![]);
+    bench(c, "inverted polygon, 10k points", &inverted);
+
+    let tiles = (0..10_000)
+        .map(|i| {
+            let (x, y) = (f64::from(i % 100), f64::from(i / 100));
+            Rect::new((x, y), (x + 1., y + 1.)).to_polygon()
+        })
+        .collect();
+    bench(c, "multipolygon, 10k members", &MultiPolygon(tiles));
+
+    let holes = (0..1_000)
+        .map(|i| {
+            reversed(&circle(
+                f64::from(i % 40) * 5.,
+                f64::from(i / 40) * 5.,
+                1.,
+                16,
+            ))
+        })
+        .collect();
+    let holed = Polygon::new(
+        reversed(&LineString::from(vec![
+            (-10., -10.),
+            (210., -10.),
+            (210., 210.),
+            (-10., 210.),
+        ])),
+        holes,
+    );
+    bench(c, "polygon, 1k interior rings", &holed);
+
+    let single_holes = (0..10_000)
+        .map(|i| {
+            let (x, y) = (f64::from(i % 100) * 10., f64::from(i / 100) * 10.);
+            Polygon::new(circle(x, y, 4., 16), vec![reversed(&circle(x, y, 2., 16))])
+        })
+        .collect();
+    bench(
+        c,
+        "multipolygon, 10k single hole members",
+        &MultiPolygon(single_holes),
+    );
+}
+
+criterion_group!(benches, normalize);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+//! Normalize a polygon with the default features, as a smoke test for WebAssembly builds
+//!
+//! Build it with `cargo build --example wasm_smoke --target wasm32-unknown-unknown`, or run it
+//! under a WASI runtime such as wasmtime with `cargo run --example wasm_smoke --target
+//! wasm32-wasip1`. It only uses the core trait, so it needs no threads, files or clock.
+
+use geo::polygon;
+use geo_normalized2::Normalized;
+
+fn main() {
+    let poly = polygon![
+        (x: 1.0, y: 1.0),
+        (x: 4.0, y: 1.0),
+        (x: 4.0, y: 4.0),
+        (x: 1.0, y: 4.0),
+        (x: 1.0, y: 1.0),
+    ];
+    let norm = poly.normalized();
+    assert!(!poly.is_normalized());
+    assert!(norm.is_normalized());
+    println!("{:?}", norm);
+}
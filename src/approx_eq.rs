@@ -0,0 +1,89 @@
+use crate::ring;
+use geo_types::{Coord, CoordNum, LineString, MultiPolygon, Polygon};
+
+/// Compare normalized geometries for the same shape, allowing for floating point noise
+pub trait ApproxEqNormalized<T: CoordNum> {
+    /// Whether `self` and `other` have the same shape, with every coordinate within `epsilon`
+    ///
+    /// The comparison is made in this order:
+    ///
+    /// 1. Polygons of a `MultiPolygon` are compared pairwise in the order they are stored, so
+    ///    both should be normalized with `sort_polygons` if their order may differ.
+    /// 2. The exterior rings are compared, then the interior rings pairwise in the order they are
+    ///    stored, see `sort_interiors`.
+    /// 3. Two rings must have the same number of coordinates. Closed rings are compared as if
+    ///    both were rotated to start at their lexicographically smallest coordinate, as done by
+    ///    `canonical_start`, so the start vertex does not matter. Rings that are not closed are
+    ///    compared from their first coordinate.
+    /// 4. Two coordinates match when both their `x` and their `y` differ by at most `epsilon`.
+    ///
+    /// The smallest coordinate is picked exactly, so rings whose smallest coordinates are tied
+    /// within `epsilon` should be normalized with `round_decimals` first. Winding is compared as
+    /// is, so normalize both geometries the same way before comparing them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{ApproxEqNormalized, Normalized};
+    /// let a = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    /// let b = polygon![
+    ///         (x: 4.0, y: 4.000000001),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.000000001),
+    ///         ];
+    ///
+    /// assert!(a.normalized().approx_eq(&b.normalized(), 1e-6));
+    /// assert!(!a.normalized().approx_eq(&b.normalized(), 1e-12));
+    /// ```
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool;
+}
+
+impl<T: CoordNum> ApproxEqNormalized<T> for LineString<T> {
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        if self.0.len() != other.0.len() || self.is_closed() != other.is_closed() {
+            return false;
+        }
+        let (a, b) = (ring::min_start(self), ring::min_start(other));
+        // The closing coordinate repeats the first one, so only the open part is compared
+        let len = self.0.len().saturating_sub(usize::from(self.is_closed()));
+        (0..len).all(|i| coord_approx_eq(self.0[(a + i) % len], other.0[(b + i) % len], epsilon))
+    }
+}
+
+impl<T: CoordNum> ApproxEqNormalized<T> for Polygon<T> {
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.interiors().len() == other.interiors().len()
+            && self.exterior().approx_eq(other.exterior(), epsilon)
+            && self
+                .interiors()
+                .iter()
+                .zip(other.interiors())
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+impl<T: CoordNum> ApproxEqNormalized<T> for MultiPolygon<T> {
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+fn coord_approx_eq<T: CoordNum>(a: Coord<T>, b: Coord<T>, epsilon: T) -> bool {
+    // Subtract the smaller value so this also works for unsigned coordinates
+    let diff = |a: T, b: T| if a > b { a - b } else { b - a };
+    diff(a.x, b.x) <= epsilon && diff(a.y, b.y) <= epsilon
+}
@@ -0,0 +1,275 @@
+use crate::wkt::normalize_wkt_with;
+use crate::{NormalizeOptions, WktError};
+use alloc::borrow::Cow;
+use core::ops::Range;
+use std::io::{BufRead, Error, ErrorKind, Write};
+
+/// The column holding the WKT geometry of a CSV file, see [`normalize_wkt_csv`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvColumn<'a> {
+    /// The column with this name in the header row
+    Name(&'a str),
+    /// The column at this 0-based position
+    Index(usize),
+}
+
+impl<'a> From<&'a str> for CsvColumn<'a> {
+    fn from(name: &'a str) -> Self {
+        CsvColumn::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn<'_> {
+    fn from(index: usize) -> Self {
+        CsvColumn::Index(index)
+    }
+}
+
+/// Normalize the WKT geometries in one column of a CSV file with the OGC winding rules
+///
+/// The first record of `reader` is the header row. It is written to `writer` unchanged and used
+/// to find the `column` when it is given by name. The geometry in `column` of every following
+/// record is normalized like [`normalize_wkt`](crate::normalize_wkt); all other fields, the
+/// separators and the line endings are copied byte for byte. The geometry is written back quoted
+/// when it was quoted before or when it contains a comma. Fields follow RFC 4180: they are
+/// separated by commas and may be quoted with `"`, with `""` for a quote inside a quoted field,
+/// and quoted fields may span several lines. Blank lines are written back unchanged.
+///
+/// A geometry that is not valid WKT stops the file with an error of kind [`InvalidData`]
+/// wrapping the [`WktError`], unless `collect_errors` is set. In that case the record is written
+/// back unchanged and the 1-based line number it starts on and its error are returned once the
+/// file is done. A missing column is reported with the kind [`InvalidInput`], and malformed
+/// quoting or a record too short to hold the column with [`InvalidData`].
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::{normalize_wkt_csv, CsvColumn};
+///
+/// let input = "id,geom\n1,\"POLYGON ((1 1, 4 1, 4 4, 1 4, 1 1))\"\n2,POLYGON ((1 1\n";
+/// let mut output = Vec::new();
+/// let errors = normalize_wkt_csv(input.as_bytes(), &mut output, "geom".into(), true).unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "id,geom\n1,\"POLYGON((1 1,1 4,4 4,4 1,1 1))\"\n2,POLYGON ((1 1\n"
+/// );
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, 3);
+/// ```
+///
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+pub fn normalize_wkt_csv<R: BufRead, W: Write>(
+    reader: R,
+    writer: W,
+    column: CsvColumn<'_>,
+    collect_errors: bool,
+) -> std::io::Result<Vec<(usize, WktError)>> {
+    normalize_wkt_csv_with(
+        reader,
+        writer,
+        column,
+        NormalizeOptions::default(),
+        collect_errors,
+    )
+}
+
+/// Same as [`normalize_wkt_csv`], normalizing with the settings given in `opts`
+pub fn normalize_wkt_csv_with<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    column: CsvColumn<'_>,
+    opts: NormalizeOptions<f64>,
+    collect_errors: bool,
+) -> std::io::Result<Vec<(usize, WktError)>> {
+    let mut errors = Vec::new();
+    let mut record = String::new();
+    let mut index = match column {
+        CsvColumn::Index(index) => Some(index),
+        CsvColumn::Name(_) => None,
+    };
+    let mut header = true;
+    let mut number = 0;
+    loop {
+        record.clear();
+        let start = number + 1;
+        let fields = match read_record(&mut reader, &mut record, &mut number)? {
+            Some(fields) => fields,
+            None => break,
+        };
+        let text = record.trim_end_matches(['\n', '\r']);
+        if text.is_empty() {
+            writer.write_all(record.as_bytes())?;
+            continue;
+        }
+        if header {
+            header = false;
+            if let CsvColumn::Name(name) = column {
+                let found = fields
+                    .iter()
+                    .position(|field| unquote(&text[field.clone()]) == name);
+                match found {
+                    Some(found) => index = Some(found),
+                    None => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("CSV header has no column named {name:?}"),
+                        ))
+                    }
+                }
+            }
+            writer.write_all(record.as_bytes())?;
+            continue;
+        }
+        let index = index.unwrap_or_default();
+        let field = match fields.get(index) {
+            Some(field) => field.clone(),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("CSV record on line {start} has no column {index}"),
+                ))
+            }
+        };
+        let raw = &text[field.clone()];
+        match normalize_wkt_with(&unquote(raw), opts) {
+            Ok(norm) => {
+                writer.write_all(&text.as_bytes()[..field.start])?;
+                if raw.starts_with('"') || norm.contains([',', '"', '\n', '\r']) {
+                    write!(writer, "\"{}\"", norm.replace('"', "\"\""))?;
+                } else {
+                    writer.write_all(norm.as_bytes())?;
+                }
+                writer.write_all(&record.as_bytes()[field.end..])?;
+            }
+            Err(err) if collect_errors => {
+                errors.push((start, err));
+                writer.write_all(record.as_bytes())?;
+            }
+            Err(err) => return Err(Error::new(ErrorKind::InvalidData, err)),
+        }
+    }
+    writer.flush()?;
+    Ok(errors)
+}
+
+/// Read the lines of the next record into `record`, counting them in `number`, and return the
+/// byte ranges of its raw fields, or `None` at the end of the input
+fn read_record<R: BufRead>(
+    reader: &mut R,
+    record: &mut String,
+    number: &mut usize,
+) -> std::io::Result<Option<Vec<Range<usize>>>> {
+    loop {
+        if reader.read_line(record)? == 0 {
+            if record.is_empty() {
+                return Ok(None);
+            }
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("CSV ends inside a quoted field, on line {number}"),
+            ));
+        }
+        *number += 1;
+        let text = record.trim_end_matches(['\n', '\r']);
+        match split_fields(text) {
+            Ok(Some(fields)) => return Ok(Some(fields)),
+            // A quoted field continues on the next line
+            Ok(None) => {}
+            Err(message) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid CSV record on line {number}: {message}"),
+                ))
+            }
+        }
+    }
+}
+
+/// The byte ranges of the raw fields of a record, quotes included, or `None` when the record
+/// ends inside a quoted field
+fn split_fields(text: &str) -> Result<Option<Vec<Range<usize>>>, &'static str> {
+    let bytes = text.as_bytes();
+    let mut fields = Vec::new();
+    let mut start = 0;
+    loop {
+        let mut end = start;
+        if bytes.get(start) == Some(&b'"') {
+            end += 1;
+            loop {
+                match bytes.get(end) {
+                    None => return Ok(None),
+                    Some(b'"') if bytes.get(end + 1) == Some(&b'"') => end += 2,
+                    Some(b'"') => break,
+                    Some(_) => end += 1,
+                }
+            }
+            end += 1;
+            if !matches!(bytes.get(end), None | Some(b',')) {
+                return Err("unexpected character after a quoted field");
+            }
+        } else {
+            while !matches!(bytes.get(end), None | Some(b',')) {
+                end += 1;
+            }
+        }
+        fields.push(start..end);
+        if end == bytes.len() {
+            return Ok(Some(fields));
+        }
+        start = end + 1;
+    }
+}
+
+/// The value of a raw field, without its quotes and with `""` turned back into `"`
+fn unquote(raw: &str) -> Cow<'_, str> {
+    match raw.strip_prefix('"').and_then(|raw| raw.strip_suffix('"')) {
+        Some(inner) if inner.contains('"') => Cow::Owned(inner.replace("\"\"", "\"")),
+        Some(inner) => Cow::Borrowed(inner),
+        None => Cow::Borrowed(raw),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_normalizes_one_column_and_keeps_the_rest() {
+        let input = "id,\"name\",geom,note\r\n\
+            1,\"a, \"\"b\"\"\",\"POLYGON ((0 0, 5 0, 5 5, 0 5, 0 0))\",x\r\n\
+            \r\n\
+            2,c,POINT (1 2),\"multi\nline\"\n\
+            3,d,\"POLYGON ((0 0,\n5 0, 5 5, 0 0))\",\n\
+            4,e,CIRCLE(1 1),y";
+        let expected = "id,\"name\",geom,note\r\n\
+            1,\"a, \"\"b\"\"\",\"POLYGON((0 0,0 5,5 5,5 0,0 0))\",x\r\n\
+            \r\n\
+            2,c,POINT(1 2),\"multi\nline\"\n\
+            3,d,\"POLYGON((0 0,5 5,5 0,0 0))\",\n\
+            4,e,CIRCLE(1 1),y";
+        for column in [CsvColumn::Name("geom"), CsvColumn::Index(2)] {
+            let mut output = Vec::new();
+            let errors = normalize_wkt_csv(input.as_bytes(), &mut output, column, true).unwrap();
+            assert_eq!(String::from_utf8(output).unwrap(), expected);
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].0, 8);
+        }
+
+        let mut output = Vec::new();
+        let err = normalize_wkt_csv(input.as_bytes(), &mut output, 2.into(), false).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.into_inner().unwrap().is::<WktError>());
+
+        let err = normalize_wkt_csv(input.as_bytes(), Vec::new(), "wkt".into(), true).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        for bad in [
+            "id,geom\n1,\"POINT(1 2)\"x\n",
+            "id,geom\n1,\"POINT(1 2)\n",
+            "id,geom\n1\n",
+        ] {
+            let err = normalize_wkt_csv(bad.as_bytes(), Vec::new(), 1.into(), true).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+}
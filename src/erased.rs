@@ -0,0 +1,103 @@
+use crate::{NormalizeOptions, Normalized};
+use alloc::boxed::Box;
+use core::any::Any;
+use geo_types::{CoordNum, Geometry};
+
+/// An object safe counterpart of [`Normalized`], for normalizing geometries whose concrete type
+/// is only known at runtime
+///
+/// [`Normalized`] returns `Self`, so it cannot be used as `dyn Normalized`. Every `Normalized`
+/// geometry implements this trait as well, so a `Box<dyn NormalizeDyn<T>>` can hold polygons,
+/// collections and tuples side by side. Use [`NormalizeDyn::as_any`] to get the concrete type
+/// back.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon, Polygon};
+/// use geo_normalized2::{NormalizeDyn, Normalized};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+/// let registry: Vec<Box<dyn NormalizeDyn<f64>>> = vec![
+///     Box::new(poly.clone()),
+///     Box::new(MultiPolygon(vec![poly.clone()])),
+/// ];
+///
+/// let norm = registry[0].normalized_boxed();
+/// assert!(norm.is_normalized_dyn());
+/// assert_eq!(norm.as_any().downcast_ref::<Polygon<f64>>(), Some(&poly.normalized()));
+/// ```
+pub trait NormalizeDyn<T: CoordNum> {
+    /// Normalize in place with the OGC winding rules, see [`Normalized::normalize_mut`]
+    fn normalize_dyn(&mut self);
+
+    /// Normalize in place with the settings given in `opts`, see
+    /// [`Normalized::normalize_mut_with`]
+    fn normalize_dyn_with(&mut self, opts: NormalizeOptions<T>);
+
+    /// Return a normalized copy, boxed behind the same trait object
+    fn normalized_boxed(&self) -> Box<dyn NormalizeDyn<T>>;
+
+    /// Whether the geometry already follows the OGC winding rules, see
+    /// [`Normalized::is_normalized`]
+    fn is_normalized_dyn(&self) -> bool;
+
+    /// The geometry as `Any`, to downcast it to its concrete type
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T, G> NormalizeDyn<T> for G
+where
+    T: CoordNum,
+    G: Normalized<T> + Clone + Any,
+{
+    fn normalize_dyn(&mut self) {
+        self.normalize_mut();
+    }
+
+    fn normalize_dyn_with(&mut self, opts: NormalizeOptions<T>) {
+        self.normalize_mut_with(opts);
+    }
+
+    fn normalized_boxed(&self) -> Box<dyn NormalizeDyn<T>> {
+        Box::new(self.normalized())
+    }
+
+    fn is_normalized_dyn(&self) -> bool {
+        self.is_normalized()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Normalize a geometry of any kind with the OGC winding rules, using the [`Geometry`] enum to
+/// erase its type
+///
+/// Convert a concrete geometry with `.into()` to call this without knowing its type.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Geometry};
+/// use geo_normalized2::{normalize_geometry, Normalized};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// let norm = normalize_geometry(&poly.clone().into());
+/// assert_eq!(norm, Geometry::Polygon(poly.normalized()));
+/// ```
+pub fn normalize_geometry<T: CoordNum>(geom: &Geometry<T>) -> Geometry<T> {
+    geom.normalized()
+}
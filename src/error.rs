@@ -0,0 +1,128 @@
+#[cfg(feature = "flatgeobuf")]
+use crate::FlatGeobufError;
+#[cfg(feature = "geojson")]
+use crate::GeoJsonError;
+#[cfg(feature = "topojson")]
+use crate::TopoJsonError;
+#[cfg(feature = "wkb")]
+use crate::WkbError;
+#[cfg(feature = "wkt")]
+use crate::WktError;
+use alloc::string::String;
+
+/// The reasons a geometry can fail to be normalized by [`Normalized::try_normalized`]
+///
+/// Rings are identified by `ring_index`, where `0` is the exterior ring of a polygon and
+/// interior ring `i` has the index `i + 1`.
+///
+/// [`Normalized::try_normalized`]: crate::Normalized::try_normalized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum NormalizeError {
+    /// The exterior ring of a polygon has no coordinates
+    #[error("polygon has an empty exterior ring")]
+    EmptyExterior,
+    /// A ring has fewer than the four coordinates needed to form a closed ring
+    #[error("ring {ring_index} has fewer than four coordinates")]
+    DegenerateRing { ring_index: usize },
+    /// A coordinate is `NaN` or infinite, `point_index` is its position within the ring
+    #[error("coordinate {point_index} of ring {ring_index} is not finite")]
+    NonFinite {
+        ring_index: usize,
+        point_index: usize,
+    },
+    /// A ring encloses an area of exactly zero, so it has no defined winding. Only reported
+    /// when [`NormalizeOptions::reject_zero_area`] is set.
+    ///
+    /// [`NormalizeOptions::reject_zero_area`]: crate::NormalizeOptions::reject_zero_area
+    #[error("ring {ring_index} has zero area")]
+    ZeroArea { ring_index: usize },
+    /// A coordinate lies outside of [`NormalizeOptions::coordinate_range`], `point_index` is its
+    /// position within the ring
+    ///
+    /// [`NormalizeOptions::coordinate_range`]: crate::NormalizeOptions::coordinate_range
+    #[error("coordinate {point_index} of ring {ring_index} is out of range")]
+    OutOfRange {
+        ring_index: usize,
+        point_index: usize,
+    },
+    /// A polygon has more than [`NormalizeOptions::max_vertices`] coordinates, `count` is the
+    /// number of coordinates of all its rings
+    ///
+    /// [`NormalizeOptions::max_vertices`]: crate::NormalizeOptions::max_vertices
+    #[error("polygon has {count} vertices, more than allowed")]
+    TooManyVertices { count: usize },
+}
+
+/// An error from parsing an [`Orientation`](crate::Orientation) from a string
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "unknown orientation {input:?}, expected one of ogc, geojson, esri, kml, d3 or \
+     oppositeinterior"
+)]
+pub struct ParseOrientationError {
+    /// The string that names no orientation
+    pub input: String,
+}
+
+/// Any error returned by this crate
+///
+/// Every variant wraps the error of one API and returns it from [`source`], so error reporters
+/// such as `anyhow` print the whole chain. The parsing variants are only present when their
+/// feature is enabled. Each wrapped error converts into this type with `?`.
+///
+/// [`source`]: core::error::Error::source
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// A geometry could not be normalized
+    #[error("failed to normalize geometry")]
+    Normalize(#[from] NormalizeError),
+    /// A WKT string could not be read
+    #[cfg(feature = "wkt")]
+    #[error("failed to read WKT")]
+    Wkt(#[from] WktError),
+    /// A WKB blob could not be read
+    #[cfg(feature = "wkb")]
+    #[error("failed to read WKB")]
+    Wkb(#[from] WkbError),
+    /// A FlatGeobuf feature could not be read
+    #[cfg(feature = "flatgeobuf")]
+    #[error("failed to read FlatGeobuf")]
+    FlatGeobuf(#[from] FlatGeobufError),
+    /// A GeoJSON document could not be read
+    #[cfg(feature = "geojson")]
+    #[error("failed to read GeoJSON")]
+    GeoJson(#[from] GeoJsonError),
+    /// A TopoJSON topology could not be read
+    #[cfg(feature = "topojson")]
+    #[error("failed to read TopoJSON")]
+    TopoJson(#[from] TopoJsonError),
+    /// An orientation name could not be parsed
+    #[error("failed to parse orientation")]
+    Orientation(#[from] ParseOrientationError),
+}
+
+#[cfg(all(test, feature = "wkt", feature = "wkb", feature = "geojson"))]
+mod tests {
+    use super::*;
+    use crate::{normalize_geojson_str, normalize_wkb, normalize_wkt};
+
+    #[test]
+    fn parse_errors_convert_into_the_crate_error() {
+        use std::error::Error as _;
+
+        let err = Error::from(normalize_wkt("POLYGON (").unwrap_err());
+        assert!(matches!(err, Error::Wkt(_)));
+        assert!(err.source().unwrap().to_string().starts_with("invalid WKT"));
+        let err = Error::from(normalize_wkb(&[2]).unwrap_err());
+        assert!(matches!(err, Error::Wkb(_)));
+        assert!(err.source().unwrap().to_string().starts_with("invalid WKB"));
+        let err = Error::from(normalize_geojson_str("{}").unwrap_err());
+        assert_eq!(err.to_string(), "failed to read GeoJSON");
+        assert!(err
+            .source()
+            .unwrap()
+            .to_string()
+            .starts_with("invalid GeoJSON"));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{NormalizeOptions, Normalized};
+use alloc::string::{String, ToString};
+use flatgeobuf::{FallibleStreamingIterator, FeatureIter, FgbFeature};
+use geo_traits::to_geo::ToGeoGeometry;
+use geo_types::Geometry;
+
+/// An error from reading a FlatGeobuf feature
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid FlatGeobuf: {message}")]
+pub struct FlatGeobufError {
+    /// What was wrong with the input
+    pub message: String,
+}
+
+fn error(message: impl ToString) -> FlatGeobufError {
+    FlatGeobufError {
+        message: message.to_string(),
+    }
+}
+
+/// Normalize the geometry of every feature of a [`flatgeobuf`](https://crates.io/crates/flatgeobuf)
+/// feature iterator with the OGC winding rules, yielding geo-types geometries
+///
+/// Features are read one at a time, as the returned iterator is advanced, so files larger than
+/// memory can be normalized; each geometry is converted and normalized with
+/// [`Normalized::normalize_mut_with`], so all non-areal geometries are passed through unchanged.
+/// Features without a geometry give `None`. Z and M values are dropped, as geo-types has no room
+/// for them, and empty points, which geo-types cannot hold, are reported as errors. The iterator
+/// ends after the first error.
+///
+/// # Examples
+///
+/// ```
+/// use flatgeobuf::{FgbReader, FgbWriter, GeometryType};
+/// use geo::{polygon, Geometry};
+/// use geo_normalized2::{normalize_fgb_features, Normalized};
+///
+/// // Anti-clockwise winding order for outer ring
+/// let bad = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+/// let mut fgb = FgbWriter::create("shapes", GeometryType::Polygon).unwrap();
+/// fgb.add_feature_geom(Geometry::from(bad.clone()), |_| {}).unwrap();
+/// let mut file = Vec::new();
+/// fgb.write(&mut file).unwrap();
+///
+/// let features = FgbReader::open(file.as_slice()).unwrap().select_all_seq().unwrap();
+/// for geometry in normalize_fgb_features(features) {
+///     assert_eq!(geometry.unwrap(), Some(bad.normalized().into()));
+/// }
+/// ```
+pub fn normalize_fgb_features<R, S>(features: FeatureIter<R, S>) -> NormalizedFgbFeatures<R, S>
+where
+    FeatureIter<R, S>: FallibleStreamingIterator<Item = FgbFeature, Error = flatgeobuf::Error>,
+{
+    normalize_fgb_features_with(features, NormalizeOptions::default())
+}
+
+/// Same as [`normalize_fgb_features`], normalizing with the settings given in `opts`
+pub fn normalize_fgb_features_with<R, S>(
+    features: FeatureIter<R, S>,
+    opts: NormalizeOptions<f64>,
+) -> NormalizedFgbFeatures<R, S>
+where
+    FeatureIter<R, S>: FallibleStreamingIterator<Item = FgbFeature, Error = flatgeobuf::Error>,
+{
+    NormalizedFgbFeatures {
+        features,
+        opts,
+        done: false,
+    }
+}
+
+/// An iterator over the normalized geometries of a FlatGeobuf feature iterator, created by
+/// [`normalize_fgb_features`]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NormalizedFgbFeatures<R, S> {
+    features: FeatureIter<R, S>,
+    opts: NormalizeOptions<f64>,
+    done: bool,
+}
+
+impl<R, S> NormalizedFgbFeatures<R, S> {
+    /// The feature iterator being read
+    pub fn into_inner(self) -> FeatureIter<R, S> {
+        self.features
+    }
+}
+
+impl<R, S> Iterator for NormalizedFgbFeatures<R, S>
+where
+    FeatureIter<R, S>: FallibleStreamingIterator<Item = FgbFeature, Error = flatgeobuf::Error>,
+{
+    type Item = Result<Option<Geometry<f64>>, FlatGeobufError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let geometry = match self.features.next() {
+            Ok(Some(feature)) => normalize_feature(feature, self.opts),
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(err) => Err(error(err)),
+        };
+        self.done = geometry.is_err();
+        Some(geometry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.features.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+fn normalize_feature(
+    feature: &FgbFeature,
+    opts: NormalizeOptions<f64>,
+) -> Result<Option<Geometry<f64>>, FlatGeobufError> {
+    let geom = match feature.geometry_trait().map_err(error)? {
+        Some(geom) => geom,
+        None => return Ok(None),
+    };
+    let mut norm = geom
+        .try_to_geometry()
+        .ok_or_else(|| error("empty points are not supported"))?;
+    norm.normalize_mut_with(opts);
+    Ok(Some(norm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{get_bad_outer_bad_inner_poly, get_bad_outer_poly};
+    use crate::Orientation;
+    use flatgeobuf::{FgbReader, FgbWriter, FgbWriterOptions, GeometryType};
+    use geo_types::{line_string, point, MultiPolygon};
+    use std::io::Cursor;
+
+    fn write_fgb(geometries: &[Geometry<f64>]) -> Vec<u8> {
+        // Store the type of each feature, so polygons are not promoted to multipolygons, and
+        // leave out the spatial index, which would sort the features
+        let opts = FgbWriterOptions {
+            write_index: false,
+            detect_type: false,
+            promote_to_multi: false,
+            ..Default::default()
+        };
+        let mut fgb = FgbWriter::create_with_options("test", GeometryType::Unknown, opts).unwrap();
+        for geometry in geometries {
+            fgb.add_feature_geom(geometry.clone(), |_| {}).unwrap();
+        }
+        let mut out = Vec::new();
+        fgb.write(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn fgb_features_are_normalized_as_they_are_read() {
+        let (good, bad) = get_bad_outer_poly();
+        let (good_holed, bad_holed) = get_bad_outer_bad_inner_poly();
+        let line: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+        let pt: Geometry<f64> = point!(x: 2., y: 3.).into();
+        let fgb = write_fgb(&[
+            bad.clone().into(),
+            line.clone(),
+            MultiPolygon::new(vec![bad_holed]).into(),
+            pt.clone(),
+        ]);
+
+        let features = FgbReader::open(Cursor::new(&fgb))
+            .unwrap()
+            .select_all_seq()
+            .unwrap();
+        let norm = normalize_fgb_features(features)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            norm,
+            vec![
+                Some(good.into()),
+                Some(line),
+                Some(MultiPolygon::new(vec![good_holed]).into()),
+                Some(pt),
+            ]
+        );
+
+        let features = FgbReader::open(Cursor::new(&fgb))
+            .unwrap()
+            .select_all()
+            .unwrap();
+        let opts = NormalizeOptions::builder()
+            .orientation(Orientation::GeoJson)
+            .build();
+        let norm = normalize_fgb_features_with(features, opts)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(norm, Some(bad.normalized_with(opts).into()));
+    }
+
+    #[test]
+    fn fgb_read_errors_end_the_stream() {
+        let (good, bad) = get_bad_outer_poly();
+        let mut fgb = write_fgb(&[bad.clone().into(), bad.into()]);
+        // Cut the last feature short
+        fgb.truncate(fgb.len() - 8);
+        let features = FgbReader::open(Cursor::new(&fgb))
+            .unwrap()
+            .select_all_seq()
+            .unwrap();
+        let mut norm = normalize_fgb_features(features);
+        assert_eq!(norm.next(), Some(Ok(Some(good.into()))));
+        let err = norm.next().unwrap().unwrap_err();
+        assert!(err.to_string().starts_with("invalid FlatGeobuf"));
+        assert_eq!(norm.next(), None);
+    }
+}
@@ -0,0 +1,401 @@
+use crate::{normalize_report_with, NormalizeOptions, Orientation};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use geo_types::{Coord, LineString, Polygon};
+use geojson::{FeatureReader, FeatureWriter, GeoJson, GeometryValue, Position};
+
+/// An error from reading a GeoJSON document
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid GeoJSON: {message}")]
+pub struct GeoJsonError {
+    /// What was wrong with the input
+    pub message: String,
+}
+
+fn error(message: impl ToString) -> GeoJsonError {
+    GeoJsonError {
+        message: message.to_string(),
+    }
+}
+
+/// Normalize the polygon winding of a [`geojson`](https://crates.io/crates/geojson) geometry to
+/// the RFC 7946 right-hand rule: counter-clockwise exterior rings and clockwise interior rings
+///
+/// `GeometryValue` is the type that the geojson crate called `Value` before its 1.0 release.
+/// `Polygon` and `MultiPolygon` geometries are normalized, also within a `GeometryCollection`;
+/// all other geometries are returned untouched. The rings are wound as geo-types polygons would
+/// be by [`Normalized::normalized_with`], but reversed as a whole in place, so positions keep any
+/// altitude. The foreign members and bounding boxes of nested geometries are kept too.
+///
+/// # Examples
+///
+/// ```
+/// use geojson::GeometryValue;
+/// use geo_normalized2::normalize_geojson;
+///
+/// let square = GeometryValue::new_polygon([[[1., 1.], [1., 4.], [4., 4.], [4., 1.], [1., 1.]]]);
+/// assert_eq!(
+///     normalize_geojson(&square),
+///     GeometryValue::new_polygon([[[1., 1.], [4., 1.], [4., 4.], [1., 4.], [1., 1.]]])
+/// );
+/// ```
+///
+/// [`Normalized::normalized_with`]: crate::Normalized::normalized_with
+pub fn normalize_geojson(value: &GeometryValue) -> GeometryValue {
+    normalize_geojson_with(value, Orientation::GeoJson)
+}
+
+/// Same as [`normalize_geojson`], producing the winding of the given `orientation`
+pub fn normalize_geojson_with(value: &GeometryValue, orientation: Orientation) -> GeometryValue {
+    let mut norm = value.clone();
+    normalize_value(&mut norm, orientation);
+    norm
+}
+
+/// Parse a GeoJSON document, normalize its geometries like [`normalize_geojson`] and write it
+/// back out
+///
+/// The document may be a geometry, a `Feature` or a `FeatureCollection`, and is read and
+/// written with the geojson crate. Properties and foreign members are kept as JSON values, in
+/// their input order, but the output is written without insignificant whitespace and numbers
+/// are written as serde_json formats them. Documents nested more than 128 levels deep are
+/// rejected.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_geojson_str;
+///
+/// let feature = r#"{"type": "Feature", "properties": {"name": "a"},
+///     "geometry": {"type": "Polygon", "coordinates": [[[1, 1], [1, 4], [4, 4], [4, 1], [1, 1]]]}}"#;
+/// assert_eq!(
+///     normalize_geojson_str(feature).unwrap(),
+///     r#"{"type":"Feature","geometry":{"type":"Polygon","coordinates":[[[1.0,1.0],[4.0,1.0],[4.0,4.0],[1.0,4.0],[1.0,1.0]]]},"properties":{"name":"a"}}"#
+/// );
+/// ```
+pub fn normalize_geojson_str(input: &str) -> Result<String, GeoJsonError> {
+    normalize_geojson_str_with(input, Orientation::GeoJson)
+}
+
+/// Same as [`normalize_geojson_str`], producing the winding of the given `orientation`
+pub fn normalize_geojson_str_with(
+    input: &str,
+    orientation: Orientation,
+) -> Result<String, GeoJsonError> {
+    let mut doc = input.parse::<GeoJson>().map_err(error)?;
+    match &mut doc {
+        GeoJson::Geometry(geometry) => normalize_value(&mut geometry.value, orientation),
+        GeoJson::Feature(feature) => normalize_feature(feature, orientation),
+        GeoJson::FeatureCollection(collection) => {
+            for feature in &mut collection.features {
+                normalize_feature(feature, orientation);
+            }
+        }
+    }
+    Ok(doc.to_string())
+}
+
+/// Read a GeoJSON `FeatureCollection` from `reader` one feature at a time, normalize the
+/// geometry of each like [`normalize_geojson`] and write it to `writer` as soon as it is done
+///
+/// The features are read with the geojson crate's [`FeatureReader`] and written with its
+/// [`FeatureWriter`], so only one feature is held in memory at a time, whatever the size of the
+/// collection. Properties are written back as JSON values, and features without a geometry or
+/// with non-areal geometries are passed through. Foreign members of the collection itself are
+/// not copied. An invalid document is returned as an error of kind [`InvalidData`], wrapping a
+/// [`GeoJsonError`]; the features before it have then already been written.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_geojson_reader;
+///
+/// let input = r#"{"type": "FeatureCollection", "features": [{"type": "Feature", "properties": null,
+///     "geometry": {"type": "Polygon", "coordinates": [[[1, 1], [1, 4], [4, 4], [4, 1], [1, 1]]]}}]}"#;
+/// let mut output = Vec::new();
+/// normalize_geojson_reader(input.as_bytes(), &mut output).unwrap();
+/// assert!(String::from_utf8(output)
+///     .unwrap()
+///     .contains(r#"[[[1.0,1.0],[4.0,1.0],[4.0,4.0],[1.0,4.0],[1.0,1.0]]]"#));
+/// ```
+///
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+pub fn normalize_geojson_reader<R: std::io::Read, W: std::io::Write>(
+    reader: R,
+    writer: W,
+) -> std::io::Result<()> {
+    normalize_geojson_reader_with(reader, writer, Orientation::GeoJson)
+}
+
+/// Same as [`normalize_geojson_reader`], producing the winding of the given `orientation`
+pub fn normalize_geojson_reader_with<R: std::io::Read, W: std::io::Write>(
+    reader: R,
+    writer: W,
+    orientation: Orientation,
+) -> std::io::Result<()> {
+    let mut out = FeatureWriter::from_writer(writer);
+    for feature in FeatureReader::from_reader(reader).features() {
+        let mut feature = feature.map_err(io_error)?;
+        normalize_feature(&mut feature, orientation);
+        out.write_feature(&feature).map_err(io_error)?;
+    }
+    out.finish().map_err(io_error)?;
+    out.flush().map_err(io_error)
+}
+
+/// Pass IO errors through, and wrap every other error of the geojson crate in a
+/// [`GeoJsonError`]
+fn io_error(err: geojson::Error) -> std::io::Error {
+    match err {
+        geojson::Error::Io(err) => err,
+        err => std::io::Error::new(std::io::ErrorKind::InvalidData, error(err)),
+    }
+}
+
+fn normalize_feature(feature: &mut geojson::Feature, orientation: Orientation) {
+    if let Some(geometry) = &mut feature.geometry {
+        normalize_value(&mut geometry.value, orientation);
+    }
+}
+
+fn normalize_value(value: &mut GeometryValue, orientation: Orientation) {
+    match value {
+        GeometryValue::Polygon { coordinates } => normalize_rings(coordinates, orientation),
+        GeometryValue::MultiPolygon { coordinates } => coordinates
+            .iter_mut()
+            .for_each(|rings| normalize_rings(rings, orientation)),
+        GeometryValue::GeometryCollection { geometries } => geometries
+            .iter_mut()
+            .for_each(|geometry| normalize_value(&mut geometry.value, orientation)),
+        _ => {}
+    }
+}
+
+/// Reverse the rings of a Polygon's coordinates that normalization would reverse
+fn normalize_rings(rings: &mut [Vec<Position>], orientation: Orientation) {
+    let Some((exterior, interiors)) = rings.split_first() else {
+        return;
+    };
+    let line = |positions: &Vec<Position>| positions.iter().map(coord).collect::<LineString>();
+    let poly = Polygon::new(line(exterior), interiors.iter().map(line).collect());
+    let opts = NormalizeOptions {
+        orientation,
+        ..Default::default()
+    };
+    let (_, report) = normalize_report_with(&poly, opts);
+    if report.exterior_reversed {
+        rings[0].reverse();
+    }
+    for i in report.reversed_interiors {
+        rings[i + 1].reverse();
+    }
+}
+
+/// The x and y of a position. Positions with fewer than two values, which the geojson crate does
+/// not read, have no winding to offer, so they count as NaN.
+fn coord(position: &Position) -> Coord<f64> {
+    match position.as_slice() {
+        [x, y, ..] => Coord { x: *x, y: *y },
+        _ => Coord {
+            x: f64::NAN,
+            y: f64::NAN,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use geojson::Geometry;
+
+    fn square(size: f64, counter_clockwise: bool) -> Vec<Position> {
+        let mut ring = vec![
+            Position::from([0., 0.]),
+            Position::from([0., size]),
+            Position::from([size, size]),
+            Position::from([size, 0.]),
+            Position::from([0., 0.]),
+        ];
+        if counter_clockwise {
+            ring.reverse();
+        }
+        ring
+    }
+
+    #[test]
+    fn can_normalize_geojson() {
+        let holed = |ccw| vec![square(50., ccw), square(10., !ccw)];
+        assert_eq!(
+            normalize_geojson(&GeometryValue::Polygon {
+                coordinates: holed(false)
+            }),
+            GeometryValue::Polygon {
+                coordinates: holed(true)
+            }
+        );
+        let multi = |ccw| GeometryValue::MultiPolygon {
+            coordinates: vec![holed(ccw), holed(ccw)],
+        };
+        assert_eq!(normalize_geojson(&multi(false)), multi(true));
+        assert_eq!(normalize_geojson(&multi(true)), multi(true));
+        assert_eq!(
+            normalize_geojson_with(&multi(true), Orientation::Ogc),
+            multi(false)
+        );
+    }
+
+    #[test]
+    fn geojson_keeps_altitudes() {
+        let mut ring = square(4., false);
+        for (i, position) in ring.iter_mut().enumerate() {
+            *position = Position::from(vec![position[0], position[1], i as f64]);
+        }
+        let poly = GeometryValue::Polygon {
+            coordinates: vec![ring.clone()],
+        };
+        ring.reverse();
+        assert_eq!(
+            normalize_geojson(&poly),
+            GeometryValue::Polygon {
+                coordinates: vec![ring]
+            }
+        );
+    }
+
+    #[test]
+    fn can_normalize_geojson_geometry_collection() {
+        let point = Geometry::new_point([1., 2.]);
+        let line = Geometry::new_line_string(square(4., false));
+        let collection = |ccw| GeometryValue::GeometryCollection {
+            geometries: vec![
+                point.clone(),
+                line.clone(),
+                Geometry::new(GeometryValue::Polygon {
+                    coordinates: vec![square(4., ccw)],
+                }),
+            ],
+        };
+        assert_eq!(normalize_geojson(&collection(false)), collection(true));
+        assert_eq!(normalize_geojson(&point.value), point.value);
+        assert_eq!(normalize_geojson(&line.value), line.value);
+    }
+
+    #[test]
+    fn geojson_opposite_interior_keeps_exterior() {
+        let poly = |hole_ccw| GeometryValue::Polygon {
+            coordinates: vec![square(9., true), square(2., hole_ccw)],
+        };
+        assert_eq!(
+            normalize_geojson_with(&poly(true), Orientation::OppositeInterior),
+            poly(false)
+        );
+    }
+
+    #[test]
+    fn can_normalize_geojson_documents() {
+        let collection = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "id": 7,
+                    "properties": {"name": "holed \"square\"", "z": 2.5, "a": [true, null]},
+                    "geometry": {
+                        "type": "MultiPolygon",
+                        "coordinates": [[
+                            [[0, 0, 9.5], [0, 50, 9.5], [50, 50, 9.5], [50, 0, 9.5], [0, 0, 9.5]],
+                            [[10, 10], [20, 10], [20, 20], [10, 20], [10, 10]]
+                        ]]
+                    }
+                },
+                {"type": "Feature", "properties": null, "geometry": null}
+            ]
+        }"#;
+        let norm = normalize_geojson_str(collection).unwrap();
+        assert!(
+            norm.contains(r#""properties":{"name":"holed \"square\"","z":2.5,"a":[true,null]}"#)
+        );
+        assert!(norm.contains(concat!(
+            r#"[[[0.0,0.0,9.5],[50.0,0.0,9.5],[50.0,50.0,9.5],[0.0,50.0,9.5],[0.0,0.0,9.5]],"#,
+            r#"[[10.0,10.0],[10.0,20.0],[20.0,20.0],[20.0,10.0],[10.0,10.0]]]"#
+        )));
+        assert!(norm.contains(r#""geometry":null"#));
+        assert_eq!(normalize_geojson_str(&norm), Ok(norm.clone()));
+
+        let ogc = normalize_geojson_str_with(&norm, Orientation::Ogc).unwrap();
+        assert_ne!(ogc, norm);
+        assert_eq!(normalize_geojson_str(&ogc), Ok(norm));
+    }
+
+    #[test]
+    fn geojson_reports_errors() {
+        // The geojson crate reads positions that are too short, they are passed through
+        let short = r#"{"type":"Polygon","coordinates":[[[1.0,1.0],[1.0]]]}"#;
+        assert_eq!(normalize_geojson_str(short), Ok(short.to_string()));
+        assert!(normalize_geojson_str(r#"{"type": "Polygon"}"#).is_err());
+        assert!(normalize_geojson_str(r#"{"coordinates": []}"#).is_err());
+        assert!(normalize_geojson_str(r#"{"type": "Point", "coordinates": [1, 2]"#).is_err());
+        let nested = format!(r#"{{"type":"Point","coordinates":{}"#, "[".repeat(100_000));
+        let err = normalize_geojson_str(&nested).unwrap_err();
+        assert!(err.message.contains("recursion limit exceeded"));
+        assert!(matches!(Error::from(err), Error::GeoJson(_)));
+    }
+
+    #[test]
+    fn geojson_reader_writes_each_feature_as_it_is_read() {
+        /// A reader that fails once the first feature has been read, so features written before
+        /// that must have been streamed
+        struct FailAfter<'a>(&'a [u8]);
+        impl std::io::Read for FailAfter<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() {
+                    return Err(std::io::Error::other("connection lost"));
+                }
+                let len = buf.len().min(self.0.len());
+                buf[..len].copy_from_slice(&self.0[..len]);
+                self.0 = &self.0[len..];
+                Ok(len)
+            }
+        }
+
+        let first = r#"{"type": "Feature", "properties": {"name": "a \"b\"", "n": 1.5},
+             "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [0, 5], [5, 5], [5, 0], [0, 0]]]}}"#;
+        let second = r#"{"type": "Feature", "properties": null,
+             "geometry": {"type": "LineString", "coordinates": [[0, 5], [0, 0]]}}"#;
+        let input = format!(
+            r#"{{"type": "FeatureCollection", "features": [{}, {}]}}"#,
+            first, second
+        );
+        let mut output = Vec::new();
+        normalize_geojson_reader(input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(r#""properties":{"name":"a \"b\"","n":1.5}"#));
+        assert!(output.contains(r#"[[[0.0,0.0],[5.0,0.0],[5.0,5.0],[0.0,5.0],[0.0,0.0]]]"#));
+        assert!(output.contains(r#""coordinates":[[0.0,5.0],[0.0,0.0]]"#));
+        let collection = output.parse::<geojson::FeatureCollection>().unwrap();
+        assert_eq!(collection.features.len(), 2);
+
+        let mut ogc = Vec::new();
+        normalize_geojson_reader_with(output.as_bytes(), &mut ogc, Orientation::Ogc).unwrap();
+        assert!(String::from_utf8(ogc)
+            .unwrap()
+            .contains(r#"[[[0.0,0.0],[0.0,5.0],[5.0,5.0],[5.0,0.0],[0.0,0.0]]]"#));
+
+        let partial = format!(r#"{{"type": "FeatureCollection", "features": [{}, "#, first);
+        let mut streamed = Vec::new();
+        let err = normalize_geojson_reader(FailAfter(partial.as_bytes()), &mut streamed);
+        assert!(err.is_err());
+        assert!(String::from_utf8(streamed)
+            .unwrap()
+            .contains(r#"[[[0.0,0.0],[5.0,0.0],[5.0,5.0],[0.0,5.0],[0.0,0.0]]]"#));
+
+        let err = normalize_geojson_reader(
+            &br#"{"type": "FeatureCollection", "features": [{"#[..],
+            Vec::new(),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.into_inner().unwrap().is::<GeoJsonError>());
+    }
+}
@@ -0,0 +1,484 @@
+use crate::{normalize_report_with, NormalizeOptions, Orientation};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use geo_types::{Coord, LineString, Polygon};
+use geozero::error::{GeozeroError, Result};
+use geozero::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+
+/// A [`geozero`](https://crates.io/crates/geozero) processor that normalizes the polygon winding
+/// of the geometries passing through it, and forwards them to an inner processor
+///
+/// Put it in front of any geozero sink, such as a GeoJSON, WKT or FlatGeobuf writer, and process
+/// a source into it as into the sink itself. The rings of each `Polygon`, also within a
+/// `MultiPolygon` or `GeometryCollection`, are held until the polygon ends and are then forwarded
+/// in the winding of the orientation, reversing whole rings so any Z, M or time values are kept.
+/// All other geometries, properties and feature events are forwarded unchanged, as they arrive.
+/// The inner processor's requested dimensions are passed on to the source.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Geometry};
+/// use geo_normalized2::{GeozeroNormalizer, Normalized};
+/// use geozero::geo_types::GeoWriter;
+/// use geozero::GeozeroGeometry;
+///
+/// // Anti-clockwise winding order for outer ring
+/// let bad = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// let mut normalizer = GeozeroNormalizer::new(GeoWriter::new());
+/// Geometry::from(bad.clone()).process_geom(&mut normalizer).unwrap();
+/// let norm = normalizer.into_inner().take_geometry().unwrap();
+/// assert_eq!(norm, Geometry::from(bad.normalized()));
+/// ```
+#[derive(Debug)]
+pub struct GeozeroNormalizer<P> {
+    inner: P,
+    orientation: Orientation,
+    polygon: Option<BufferedPolygon>,
+}
+
+/// A polygon whose rings are held until it ends
+#[derive(Debug)]
+struct BufferedPolygon {
+    tagged: bool,
+    size: usize,
+    idx: usize,
+    rings: Vec<BufferedRing>,
+}
+
+#[derive(Debug)]
+struct BufferedRing {
+    size: usize,
+    idx: usize,
+    vertices: Vec<Vertex>,
+}
+
+/// A coordinate, as it was given to `xy` or `coordinate`
+#[derive(Debug, Clone, Copy)]
+enum Vertex {
+    Xy {
+        x: f64,
+        y: f64,
+    },
+    Coordinate {
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+    },
+}
+
+impl Vertex {
+    fn xy(&self) -> Coord<f64> {
+        match *self {
+            Vertex::Xy { x, y } | Vertex::Coordinate { x, y, .. } => Coord { x, y },
+        }
+    }
+}
+
+impl<P> GeozeroNormalizer<P> {
+    /// Normalize to the OGC winding rules before forwarding to `inner`
+    pub fn new(inner: P) -> Self {
+        Self::with_orientation(inner, Orientation::Ogc)
+    }
+
+    /// Normalize to the winding of the given `orientation` before forwarding to `inner`
+    pub fn with_orientation(inner: P, orientation: Orientation) -> Self {
+        GeozeroNormalizer {
+            inner,
+            orientation,
+            polygon: None,
+        }
+    }
+
+    /// The inner processor
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// The inner processor, for events that may not arrive within a polygon
+    fn forward(&mut self) -> Result<&mut P> {
+        match self.polygon {
+            Some(_) => Err(GeozeroError::Geometry(
+                "a polygon may only hold rings of coordinates".to_string(),
+            )),
+            None => Ok(&mut self.inner),
+        }
+    }
+
+    /// Hold a coordinate in the current ring, returning whether there is a polygon to hold it
+    fn push_vertex(&mut self, vertex: Vertex) -> Result<bool> {
+        match &mut self.polygon {
+            Some(polygon) => match polygon.rings.last_mut() {
+                Some(ring) => {
+                    ring.vertices.push(vertex);
+                    Ok(true)
+                }
+                _ => Err(GeozeroError::Geometry(
+                    "polygon coordinates must be within a ring".to_string(),
+                )),
+            },
+            None => Ok(false),
+        }
+    }
+}
+
+impl<P: GeomProcessor> GeozeroNormalizer<P> {
+    /// Forward a buffered polygon, reversing the rings that are not wound as the orientation
+    /// requires
+    fn flush_polygon(&mut self, polygon: BufferedPolygon) -> Result<()> {
+        let mut reversed = alloc::vec![false; polygon.rings.len()];
+        if let Some((exterior, interiors)) = polygon.rings.split_first() {
+            let line = |ring: &BufferedRing| {
+                ring.vertices
+                    .iter()
+                    .map(Vertex::xy)
+                    .collect::<LineString<f64>>()
+            };
+            let poly = Polygon::new(line(exterior), interiors.iter().map(line).collect());
+            let opts = NormalizeOptions {
+                orientation: self.orientation,
+                ..Default::default()
+            };
+            let (_, report) = normalize_report_with(&poly, opts);
+            reversed[0] = report.exterior_reversed;
+            for i in report.reversed_interiors {
+                reversed[i + 1] = true;
+            }
+        }
+        self.inner
+            .polygon_begin(polygon.tagged, polygon.size, polygon.idx)?;
+        for (ring, reversed) in polygon.rings.into_iter().zip(reversed) {
+            self.inner.linestring_begin(false, ring.size, ring.idx)?;
+            let mut vertices = ring.vertices;
+            if reversed {
+                vertices.reverse();
+            }
+            for (idx, vertex) in vertices.into_iter().enumerate() {
+                match vertex {
+                    Vertex::Xy { x, y } => self.inner.xy(x, y, idx)?,
+                    Vertex::Coordinate { x, y, z, m, t, tm } => {
+                        self.inner.coordinate(x, y, z, m, t, tm, idx)?
+                    }
+                }
+            }
+            self.inner.linestring_end(false, ring.idx)?;
+        }
+        self.inner.polygon_end(polygon.tagged, polygon.idx)
+    }
+}
+
+// Every event is forwarded, as the defaults of the geozero traits would drop them
+impl<P: GeomProcessor> GeomProcessor for GeozeroNormalizer<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.inner.dimensions()
+    }
+    fn multi_dim(&self) -> bool {
+        self.inner.multi_dim()
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.forward()?.srid(srid)
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if self.push_vertex(Vertex::Xy { x, y })? {
+            return Ok(());
+        }
+        self.inner.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let vertex = Vertex::Coordinate { x, y, z, m, t, tm };
+        if self.push_vertex(vertex)? {
+            return Ok(());
+        }
+        self.inner.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        match &mut self.polygon {
+            Some(polygon) if !tagged => {
+                polygon.rings.push(BufferedRing {
+                    size,
+                    idx,
+                    vertices: Vec::with_capacity(size),
+                });
+                Ok(())
+            }
+            _ => self.forward()?.linestring_begin(tagged, size, idx),
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        match self.polygon {
+            Some(_) if !tagged => Ok(()),
+            _ => self.forward()?.linestring_end(tagged, idx),
+        }
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.forward()?;
+        self.polygon = Some(BufferedPolygon {
+            tagged,
+            size,
+            idx,
+            rings: Vec::with_capacity(size),
+        });
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        match self.polygon.take() {
+            Some(polygon) => self.flush_polygon(polygon),
+            None => self.inner.polygon_end(tagged, idx),
+        }
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.forward()?.triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.forward()?.tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.forward()?.tin_end(idx)
+    }
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for GeozeroNormalizer<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.forward()?.property(idx, name, value)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for GeozeroNormalizer<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.forward()?.dataset_begin(name)
+    }
+    fn dataset_end(&mut self) -> Result<()> {
+        self.forward()?.dataset_end()
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.forward()?.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.forward()?.feature_end(idx)
+    }
+    fn properties_begin(&mut self) -> Result<()> {
+        self.forward()?.properties_begin()
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.forward()?.properties_end()
+    }
+    fn geometry_begin(&mut self) -> Result<()> {
+        self.forward()?.geometry_begin()
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        self.forward()?.geometry_end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{get_bad_outer_bad_inner_poly, get_bad_outer_poly};
+    use crate::Normalized;
+    use alloc::format;
+    use alloc::string::String;
+    use geo_types::{line_string, point, Geometry, GeometryCollection, MultiPolygon};
+    use geozero::geo_types::GeoWriter;
+    use geozero::GeozeroGeometry;
+
+    fn normalize(geom: &Geometry<f64>, orientation: Orientation) -> Geometry<f64> {
+        let mut normalizer = GeozeroNormalizer::with_orientation(GeoWriter::new(), orientation);
+        geom.process_geom(&mut normalizer).unwrap();
+        normalizer.into_inner().take_geometry().unwrap()
+    }
+
+    /// Records the events it is given, asking for Z values
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl GeomProcessor for Recorder {
+        fn dimensions(&self) -> CoordDimensions {
+            CoordDimensions::xyz()
+        }
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            _m: Option<f64>,
+            _t: Option<f64>,
+            _tm: Option<u64>,
+            idx: usize,
+        ) -> Result<()> {
+            self.0.push(format!("{} {} {:?} #{}", x, y, z, idx));
+            Ok(())
+        }
+        fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+            self.0.push(format!("polygon of {}", size));
+            Ok(())
+        }
+    }
+
+    impl PropertyProcessor for Recorder {
+        fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+            self.0.push(format!("{} = {}", name, value));
+            Ok(false)
+        }
+    }
+
+    impl FeatureProcessor for Recorder {
+        fn feature_begin(&mut self, idx: u64) -> Result<()> {
+            self.0.push(format!("feature {}", idx));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn geozero_polygons_are_normalized_as_they_pass() {
+        let (good, bad) = get_bad_outer_poly();
+        let (good_holed, bad_holed) = get_bad_outer_bad_inner_poly();
+        let line: Geometry<f64> = line_string![(x: 0., y: 0.), (x: 1., y: 1.)].into();
+        let pt: Geometry<f64> = point!(x: 2., y: 3.).into();
+        let collection = |polygon, holed| {
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::Polygon(polygon),
+                line.clone(),
+                Geometry::MultiPolygon(MultiPolygon::new(vec![holed])),
+                pt.clone(),
+            ]))
+        };
+
+        let norm = normalize(
+            &collection(bad.clone(), bad_holed.clone()),
+            Orientation::Ogc,
+        );
+        assert_eq!(norm, collection(good.clone(), good_holed.clone()));
+        assert_eq!(normalize(&norm, Orientation::Ogc), norm);
+        let opts = NormalizeOptions::builder()
+            .orientation(Orientation::GeoJson)
+            .build();
+        assert_eq!(
+            normalize(&bad.into(), Orientation::GeoJson),
+            Geometry::Polygon(good.normalized_with(opts))
+        );
+    }
+
+    #[test]
+    fn geozero_events_around_polygons_are_forwarded() {
+        let mut normalizer = GeozeroNormalizer::new(Recorder::default());
+        assert!(normalizer.multi_dim());
+        normalizer.feature_begin(7).unwrap();
+        normalizer
+            .property(0, "name", &ColumnValue::String("square"))
+            .unwrap();
+        normalizer.polygon_begin(true, 1, 0).unwrap();
+        normalizer.linestring_begin(false, 4, 0).unwrap();
+        // An anti-clockwise triangle, with Z values
+        for (idx, (x, y)) in [(0., 0.), (1., 0.), (0., 1.), (0., 0.)].iter().enumerate() {
+            normalizer
+                .coordinate(*x, *y, Some(idx as f64), None, None, None, idx)
+                .unwrap();
+        }
+        assert!(normalizer.feature_end(7).is_err());
+        normalizer.linestring_end(false, 0).unwrap();
+        normalizer.polygon_end(true, 0).unwrap();
+        assert_eq!(
+            normalizer.into_inner().0,
+            vec![
+                "feature 7",
+                "name = square",
+                "polygon of 1",
+                "0 0 Some(3.0) #0",
+                "0 1 Some(2.0) #1",
+                "1 0 Some(1.0) #2",
+                "0 0 Some(0.0) #3",
+            ]
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{ring, Normalized, Orientation, RingRole, WindingOrder};
+use core::marker::PhantomData;
+use geo_types::{Coord, CoordNum, Geometry, LineString, Polygon};
+
+/// Lazily normalize every geometry yielded by `iter`, with the OGC winding rules
+///
+/// Each geometry is normalized as it is pulled through the iterator, reusing its allocations, so
+/// streaming over a large feature source never holds more than one geometry at a time.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Geometry};
+/// use geo_normalized2::{normalize_all, Normalized};
+/// let features = vec![Geometry::Polygon(polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ])];
+///
+/// for geom in normalize_all(features) {
+///     assert!(geom.is_normalized());
+/// }
+/// ```
+pub fn normalize_all<T, I>(iter: I) -> impl Iterator<Item = Geometry<T>>
+where
+    T: CoordNum,
+    I: IntoIterator<Item = Geometry<T>>,
+{
+    iter.into_iter().map(|geom| geom.into_normalized())
+}
+
+/// Normalize, in place, every polygon of a slice with the OGC winding rules
+///
+/// This is the same as calling [`Normalized::normalize_mut`] on each element, so polygons that
+/// are already valid are left untouched and nothing is allocated.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{normalize_slice, Normalized};
+/// let mut polys = vec![polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ]];
+///
+/// normalize_slice(&mut polys);
+/// assert!(polys[0].is_normalized());
+/// ```
+pub fn normalize_slice<T: CoordNum>(polys: &mut [Polygon<T>]) {
+    for poly in polys {
+        poly.normalize_mut();
+    }
+}
+
+/// Normalize, in place, every geometry of a slice with the OGC winding rules
+///
+/// Areal geometries are normalized and all other variants are left unchanged, as for a
+/// `GeometryCollection`, without having to wrap the geometries in one.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Geometry, Point};
+/// use geo_normalized2::{normalize_geometries, Normalized};
+/// let mut geoms = vec![
+///     Geometry::Point(Point::new(1.0, 1.0)),
+///     Geometry::Polygon(polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ]),
+/// ];
+///
+/// normalize_geometries(&mut geoms);
+/// assert!(geoms.is_normalized());
+/// ```
+pub fn normalize_geometries<T: CoordNum>(geoms: &mut [Geometry<T>]) {
+    for geom in geoms {
+        geom.normalize_mut();
+    }
+}
+
+/// Iterate over the coordinates of a polygon in the order of its OGC normalized form, without
+/// building a new polygon
+///
+/// Each coordinate is yielded with the role of its ring, exterior first. The coordinates are the
+/// same as those of the rings of [`Normalized::normalized`], but rings that need reversing are
+/// read backwards from the original polygon on the fly, so nothing is allocated. This suits
+/// writing a polygon straight out to a serializer. Only rings that are not closed, which geo
+/// does not produce itself, are copied to find their winding.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon};
+/// use geo_normalized2::{normalized_coords, Normalized, RingRole};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// let coords: Vec<_> = normalized_coords(&poly).collect();
+/// assert_eq!(coords[1], (RingRole::Exterior, coord! { x: 1.0, y: 4.0 }));
+/// assert!(coords.iter().map(|(_, c)| *c).eq(poly.normalized().exterior().coords().copied()));
+/// ```
+pub fn normalized_coords<T: CoordNum>(
+    poly: &Polygon<T>,
+) -> impl Iterator<Item = (RingRole, Coord<T>)> + '_ {
+    normalized_coords_with(poly, Orientation::Ogc)
+}
+
+/// Same as [`normalized_coords`], in the order of the given `orientation`
+pub fn normalized_coords_with<T: CoordNum>(
+    poly: &Polygon<T>,
+    orientation: Orientation,
+) -> impl Iterator<Item = (RingRole, Coord<T>)> + '_ {
+    // Normalizing leaves a polygon with an empty exterior exactly as it is
+    let empty = poly.exterior().0.is_empty();
+    let exterior = orientation.exterior_winding();
+    let interior = orientation.interior_winding_within(closed_winding(poly.exterior()));
+    core::iter::once((RingRole::Exterior, poly.exterior(), exterior))
+        .chain(
+            poly.interiors()
+                .iter()
+                .map(move |ring| (RingRole::Interior, ring, interior)),
+        )
+        .flat_map(move |(role, ring, wanted)| {
+            let reverse = !empty
+                && wanted.is_some_and(|wanted| {
+                    ring::needs_reversal(closed_winding(ring), wanted, || {
+                        ring::twice_signed_area(ring)
+                    })
+                });
+            let coords = &ring.0;
+            let len = coords.len() + usize::from(!empty && !coords.is_empty() && !ring.is_closed());
+            (0..len).map(move |i| {
+                let i = if reverse { len - 1 - i } else { i };
+                // The one index past the end is the closing coordinate
+                (role, coords.get(i).copied().unwrap_or(coords[0]))
+            })
+        })
+}
+
+/// The winding a ring has once it is closed, as it is when normalizing
+fn closed_winding<T: CoordNum>(ring: &LineString<T>) -> Option<WindingOrder> {
+    if ring.is_closed() {
+        ring::winding_order(ring)
+    } else {
+        let mut ring = ring.clone();
+        ring.close();
+        ring::winding_order(&ring)
+    }
+}
+
+/// Normalize the geometries of an iterator in a chain, see [`NormalizedIteratorExt::normalized`]
+///
+/// This is implemented for every iterator whose items implement [`Normalized`], such as
+/// iterators of `Polygon`s or `Geometry`s.
+pub trait NormalizedIteratorExt<T: CoordNum>: Iterator + Sized
+where
+    Self::Item: Normalized<T>,
+{
+    /// Lazily normalize every item with the OGC winding rules as it is pulled through the
+    /// iterator, reusing its allocations
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{Normalized, NormalizedIteratorExt};
+    /// let polys = vec![polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ]];
+    ///
+    /// assert!(polys.into_iter().normalized().all(|poly| poly.is_normalized()));
+    /// ```
+    fn normalized(self) -> NormalizedIter<Self, T> {
+        NormalizedIter {
+            iter: self,
+            coord: PhantomData,
+        }
+    }
+}
+
+impl<T: CoordNum, I> NormalizedIteratorExt<T> for I
+where
+    I: Iterator,
+    I::Item: Normalized<T>,
+{
+}
+
+/// An iterator that normalizes the items of another, created by
+/// [`NormalizedIteratorExt::normalized`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NormalizedIter<I, T> {
+    iter: I,
+    coord: PhantomData<T>,
+}
+
+impl<T: CoordNum, I> Iterator for NormalizedIter<I, T>
+where
+    I: Iterator,
+    I::Item: Normalized<T>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|geom| geom.into_normalized())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{NormalizeOptions, Normalized};
+use alloc::vec::Vec;
+use core::hash::Hash;
+use geo_types::{CoordNum, LineString, Polygon};
+
+/// A hashable key for the shape of a polygon, for use in `HashMap`s and `HashSet`s
+///
+/// The key is built from the canonical form of the polygon: normalized with the OGC winding
+/// rules, every ring rotated to start at its smallest coordinate (`canonical_start`) and the
+/// interior rings sorted (`sort_interiors`). Polygons with the same rings therefore get equal
+/// keys, whatever their winding, start vertices or hole order.
+///
+/// Integer polygons are keyed by their coordinates with [`NormalizedKey::new`]. Float
+/// coordinates cannot be hashed reliably, since values that only differ by noise would get
+/// different keys, so float polygons must be rounded to a number of decimal places with
+/// [`NormalizedKey::from_rounded`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::NormalizedKey;
+/// use std::collections::HashSet;
+/// let a = polygon![
+///         (x: 1, y: 1),
+///         (x: 4, y: 1),
+///         (x: 4, y: 4),
+///         (x: 1, y: 4),
+///         (x: 1, y: 1),
+///         ];
+/// let b = polygon![
+///         (x: 4, y: 4),
+///         (x: 4, y: 1),
+///         (x: 1, y: 1),
+///         (x: 1, y: 4),
+///         (x: 4, y: 4),
+///         ];
+///
+/// let mut seen = HashSet::new();
+/// seen.insert(NormalizedKey::new(&a));
+/// assert!(seen.contains(&NormalizedKey::new(&b)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedKey<K> {
+    /// The coordinates of the canonical rings, exterior first
+    rings: Vec<Vec<(K, K)>>,
+}
+
+impl<T: CoordNum + Eq + Hash> NormalizedKey<T> {
+    /// The key of a polygon with integer coordinates
+    pub fn new(poly: &Polygon<T>) -> Self {
+        NormalizedKey::from_canonical(&canonical(poly, None), |value| value)
+    }
+}
+
+impl NormalizedKey<u64> {
+    /// The key of a polygon with float, or any other, coordinates, rounded to `decimals` decimal
+    /// places
+    ///
+    /// Coordinates are rounded like the `round_decimals` option before normalizing, so
+    /// coordinates that only differ below that precision get equal keys. Keys compare the bit
+    /// patterns of the rounded values, with `-0.0` treated as `0.0`. Values on either side of a
+    /// rounding boundary still round apart, and a `NaN` coordinate only matches the exact same
+    /// `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::NormalizedKey;
+    /// let a = polygon![
+    ///         (x: 0.1 + 0.2, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 0.3, y: 4.0),
+    ///         ];
+    /// let b = polygon![
+    ///         (x: 0.3, y: 1.0),
+    ///         (x: 0.3, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert_ne!(NormalizedKey::from_rounded(&a, 16), NormalizedKey::from_rounded(&b, 16));
+    /// assert_eq!(NormalizedKey::from_rounded(&a, 12), NormalizedKey::from_rounded(&b, 12));
+    /// ```
+    pub fn from_rounded<T: CoordNum>(poly: &Polygon<T>, decimals: u32) -> Self {
+        NormalizedKey::from_canonical(&canonical(poly, Some(decimals)), |value| {
+            // Adding zero turns -0.0 into 0.0, and leaves every other value as it is
+            (value.to_f64().unwrap_or(f64::NAN) + 0.0).to_bits()
+        })
+    }
+}
+
+impl<K> NormalizedKey<K> {
+    fn from_canonical<T: CoordNum>(poly: &Polygon<T>, key: impl Fn(T) -> K) -> Self {
+        let ring = |ring: &LineString<T>| ring.coords().map(|c| (key(c.x), key(c.y))).collect();
+        NormalizedKey {
+            rings: core::iter::once(poly.exterior())
+                .chain(poly.interiors())
+                .map(ring)
+                .collect(),
+        }
+    }
+}
+
+fn canonical<T: CoordNum>(poly: &Polygon<T>, round_decimals: Option<u32>) -> Polygon<T> {
+    poly.normalized_with(NormalizeOptions {
+        canonical_start: true,
+        sort_interiors: true,
+        round_decimals,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,29 @@
+use crate::{NormalizeOptions, Normalized, Orientation};
+use geo_types::CoordNum;
+
+/// Return a copy of a geometry wound for KML output: counter-clockwise exterior rings and
+/// clockwise interior rings, see [`Orientation::Kml`]
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::algorithm::winding_order::{Winding, WindingOrder};
+/// use geo_normalized2::normalize_for_kml;
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// let kml = normalize_for_kml(&poly);
+/// assert_eq!(kml.exterior().winding_order(), Some(WindingOrder::CounterClockwise));
+/// ```
+pub fn normalize_for_kml<T: CoordNum, G: Normalized<T> + Clone>(geom: &G) -> G {
+    geom.normalized_with(NormalizeOptions {
+        orientation: Orientation::Kml,
+        ..Default::default()
+    })
+}
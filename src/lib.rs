@@ -1,10 +1,103 @@
-use geo::algorithm::winding_order::Winding;
-use geo::{
-    CoordNum, Coordinate, GeoNum, Geometry, GeometryCollection, LineString, MultiPolygon, Polygon,
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use report::RingChanges;
+
+mod approx_eq;
+#[cfg(feature = "csv")]
+mod csv;
+mod erased;
+mod error;
+#[cfg(feature = "flatgeobuf")]
+mod flatgeobuf;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "geozero")]
+mod geozero;
+mod iter;
+mod key;
+mod kml;
+mod multi;
+mod options;
+#[cfg(feature = "rayon")]
+mod par;
+mod polygon;
+pub mod prelude;
+mod report;
+mod ring;
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(feature = "topojson")]
+mod topojson;
+#[cfg(feature = "geo-traits")]
+mod traits;
+mod validate;
+mod winding;
+#[cfg(feature = "wkb")]
+mod wkb;
+#[cfg(feature = "wkt")]
+mod wkt;
+
+#[cfg(feature = "flatgeobuf")]
+pub use self::flatgeobuf::{
+    normalize_fgb_features, normalize_fgb_features_with, FlatGeobufError, NormalizedFgbFeatures,
+};
+#[cfg(feature = "geojson")]
+pub use self::geojson::{
+    normalize_geojson, normalize_geojson_reader, normalize_geojson_reader_with,
+    normalize_geojson_str, normalize_geojson_str_with, normalize_geojson_with, GeoJsonError,
+};
+#[cfg(feature = "geozero")]
+pub use self::geozero::GeozeroNormalizer;
+#[cfg(feature = "wkb")]
+pub use self::wkb::{normalize_ewkb_hex, normalize_wkb, normalize_wkb_with, WkbError};
+pub use approx_eq::ApproxEqNormalized;
+#[cfg(feature = "csv")]
+pub use csv::{normalize_wkt_csv, normalize_wkt_csv_with, CsvColumn};
+pub use erased::{normalize_geometry, NormalizeDyn};
+pub use error::{Error, NormalizeError, ParseOrientationError};
+pub use iter::{
+    normalize_all, normalize_geometries, normalize_slice, normalized_coords,
+    normalized_coords_with, NormalizedIter, NormalizedIteratorExt,
+};
+pub use key::NormalizedKey;
+pub use kml::normalize_for_kml;
+pub use multi::IntoNormalizedMulti;
+pub use options::{NormalizeOptions, NormalizeOptionsBuilder, Orientation, YAxis};
+#[cfg(feature = "rayon")]
+pub use par::NormalizedPar;
+pub use polygon::NormalizedPolygon;
+pub use report::{normalize_report, normalize_report_with, NormalizeReport};
+#[cfg(feature = "svg")]
+pub use svg::ToSvgPath;
+#[cfg(feature = "topojson")]
+pub use topojson::{normalize_topojson, normalize_topojson_with, TopoJsonError};
+#[cfg(feature = "geo-traits")]
+pub use traits::{
+    normalize_multi_polygon_trait, normalize_multi_polygon_trait_with, normalize_polygon_trait,
+    normalize_polygon_trait_with,
 };
-use num_traits;
+pub use validate::{normalize_and_validate, validate_ogc, validate_ogc_multi, OgcViolation};
+pub use winding::{
+    exterior_winding, interior_windings, normalize_ring, normalized_with_area,
+    normalized_with_area_with, ring_signed_area, track_to_polygon, wind_ring, MatchesOrientation,
+    ReverseRings, RingRole, WindingHistogram, WindingOrder, WindingStats,
+};
+#[cfg(feature = "wkt")]
+pub use wkt::{normalize_wkt, normalize_wkt_with, WktError};
+#[cfg(all(feature = "wkt", feature = "std"))]
+pub use wkt::{normalize_wkt_lines, normalize_wkt_lines_with};
 
-pub trait Normalized<T: num_traits::Float> {
+pub trait Normalized<T: CoordNum> {
     /// This trait returns a new geo-types Polygon/Multipolygon that follows the OGC winding rules
     ///
     /// The rust geo and geo-types crates are not as strict as the OGC guidelines,
@@ -12,12 +105,30 @@ pub trait Normalized<T: num_traits::Float> {
     /// This trait returns a Polygon/Multipolygon where all outer rings are clockwise,
     /// and all inner rings are anti-clockwise.
     ///
+    /// Every non-empty ring of the result is closed: the first coordinate is repeated at the end
+    /// when it is missing, and already closed rings are left as they are.
+    ///
+    /// Coordinates are only reordered, never recomputed: with the default options every
+    /// coordinate of the result is a bit-for-bit copy of an input coordinate, and only `x` and
+    /// `y` are read to find the winding. Only the clean-up options of [`NormalizeOptions`], such
+    /// as `round_decimals` or `dedupe_consecutive`, change or remove coordinates.
+    ///
+    /// Rings without a defined winding are never reversed: rings with fewer than three distinct
+    /// points, and rings whose signed area is exactly zero, such as collinear rings or a figure
+    /// of eight with equal lobes.
+    ///
+    /// Normalization is idempotent, with any [`NormalizeOptions`]: normalizing an already
+    /// normalized geometry returns it unchanged.
+    ///
+    /// The result is unspecified when the geometry contains `NaN` or infinite coordinates, since
+    /// the winding of such rings cannot be determined. Use [`try_normalized`] to reject them.
+    ///
     /// # Examples
     ///
     /// ```
     /// // Anti-clockwise winding order for outer ring
     /// use geo::polygon;
-    /// use geo_normalized::Normalized;
+    /// use geo_normalized2::Normalized;
     /// let bad = polygon![
     ///         (x: 1.0, y: 1.0),
     ///         (x: 4.0, y: 1.0),
@@ -40,114 +151,3610 @@ pub trait Normalized<T: num_traits::Float> {
     /// assert_eq!(norm, good);
     /// ```
     ///
-    fn normalized(&self) -> Self;
-}
+    /// [`try_normalized`]: Normalized::try_normalized
+    fn normalized(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut norm = self.clone();
+        norm.normalize_mut();
+        norm
+    }
 
-/** Geometry Collections */
+    /// Return the geometry normalized, borrowing it instead of cloning when it already follows
+    /// the OGC winding rules
+    ///
+    /// This is cheaper than [`normalized`] for input that is mostly valid, since only geometries
+    /// that need fixing are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use geo::polygon;
+    /// use geo_normalized2::Normalized;
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert!(matches!(poly.normalized_cow(), Cow::Borrowed(_)));
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn normalized_cow(&self) -> Cow<'_, Self>
+    where
+        Self: Clone,
+    {
+        if self.is_normalized() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.normalized())
+        }
+    }
 
-impl<T: num_traits::Float + CoordNum + GeoNum> Normalized<T> for GeometryCollection<T> {
-    fn normalized(&self) -> Self {
-        GeometryCollection(
-            self.0
-                .iter()
-                .map(|p| match p {
-                    Geometry::Polygon { .. } => {
-                        Geometry::Polygon(p.clone().into_polygon().unwrap().normalized())
-                    }
-                    Geometry::MultiPolygon { .. } => {
-                        Geometry::MultiPolygon(p.clone().into_multi_polygon().unwrap().normalized())
-                    }
-                    _ => p.clone(),
-                })
-                .collect::<Vec<Geometry<T>>>(),
-        )
+    /// Normalize the geometry in place, following the same winding rules as [`normalized`].
+    ///
+    /// Rings are only reversed when their current winding is wrong, so an already valid
+    /// geometry is left untouched and no new allocations are made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::Normalized;
+    /// let mut poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// poly.normalize_mut();
+    /// assert_eq!(poly, polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ]);
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn normalize_mut(&mut self) {
+        self.normalize_mut_with(NormalizeOptions::default());
+    }
+
+    /// Return a new normalized geometry using the winding convention and other settings
+    /// given in `opts`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{NormalizeOptions, Normalized, Orientation};
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// // GeoJSON wants counter-clockwise outer rings
+    /// let opts = NormalizeOptions {
+    ///     orientation: Orientation::GeoJson,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(poly.normalized_with(opts), polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ]);
+    /// ```
+    fn normalized_with(&self, opts: NormalizeOptions<T>) -> Self
+    where
+        Self: Clone,
+    {
+        let mut norm = self.clone();
+        norm.normalize_mut_with(opts);
+        norm
     }
-}
 
-/** Polygons */
+    /// Return a new geometry with the opposite winding to [`normalized`]: counter-clockwise
+    /// exterior rings and clockwise interior rings
+    ///
+    /// This is the same as normalizing with [`Orientation::GeoJson`], for systems that expect
+    /// the reverse of the OGC convention. Like [`normalized`], applying it twice gives the same
+    /// result as applying it once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::Normalized;
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert_eq!(poly.denormalized(), polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ]);
+    /// assert_eq!(poly.denormalized().normalized(), poly);
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn denormalized(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.normalized_with(NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        })
+    }
 
-impl<T: num_traits::Float + CoordNum + GeoNum> Normalized<T> for MultiPolygon<T> {
-    fn normalized(&self) -> Self {
-        MultiPolygon::from(
-            self.0
-                .iter()
-                .map(|x| x.normalized())
-                .collect::<Vec<Polygon<T>>>(),
-        )
+    /// Return a copy where only the interior rings are fixed, to wind opposite to the exterior
+    /// ring of their polygon, while the exterior ring is kept exactly as it is
+    ///
+    /// This is [`normalized_with`] using [`Orientation::OppositeInterior`], for data whose
+    /// exterior winding is authoritative. When an exterior ring has no defined winding, the
+    /// interior rings of its polygon are left untouched too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo::algorithm::winding_order::Winding;
+    /// use geo_normalized2::Normalized;
+    /// let poly = polygon!(
+    ///     exterior: [
+    ///         (x: 0.0, y: 0.0),
+    ///         (x: 9.0, y: 0.0),
+    ///         (x: 9.0, y: 9.0),
+    ///         (x: 0.0, y: 9.0),
+    ///         (x: 0.0, y: 0.0),
+    ///     ],
+    ///     interiors: [
+    ///         [
+    ///             (x: 1.0, y: 1.0),
+    ///             (x: 2.0, y: 1.0),
+    ///             (x: 2.0, y: 2.0),
+    ///             (x: 1.0, y: 2.0),
+    ///             (x: 1.0, y: 1.0),
+    ///         ],
+    ///     ],
+    /// );
+    ///
+    /// let norm = poly.normalize_interiors();
+    /// assert_eq!(norm.exterior(), poly.exterior());
+    /// assert!(norm.interiors()[0].is_cw());
+    /// ```
+    ///
+    /// [`normalized_with`]: Normalized::normalized_with
+    fn normalize_interiors(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.normalized_with(NormalizeOptions {
+            orientation: Orientation::OppositeInterior,
+            ..Default::default()
+        })
     }
-}
 
-impl<T: num_traits::Float + CoordNum + GeoNum> Normalized<T> for Polygon<T> {
-    fn normalized(&self) -> Self {
-        normalized_polygon(self)
+    /// Same as [`normalized_with`], but checks that normalizing did not change the area of the
+    /// geometry
+    ///
+    /// Reversing rings never changes the area, so this is a safety net for the cleanup options:
+    /// a change in the unsigned area larger than a relative difference of `sqrt(epsilon)` means
+    /// the shape was altered. Options that move coordinates, `round_decimals` and a non-zero
+    /// `collinear_tolerance`, can legitimately change the area by more than that. The areas
+    /// are found with geo's `Area`, so this needs the `std` feature.
+    ///
+    /// # Panics
+    ///
+    /// When the unsigned area of the normalized geometry differs from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{NormalizeOptions, Normalized};
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// let opts = NormalizeOptions::builder()
+    ///     .dedupe_consecutive(true)
+    ///     .remove_collinear(true)
+    ///     .build();
+    /// assert!(poly.normalized_checked(opts).is_normalized());
+    /// ```
+    ///
+    /// [`normalized_with`]: Normalized::normalized_with
+    #[cfg(feature = "std")]
+    fn normalized_checked(&self, opts: NormalizeOptions<T>) -> Self
+    where
+        Self: Clone + geo::Area<T>,
+        T: geo_types::CoordFloat,
+    {
+        let norm = self.normalized_with(opts);
+        let (before, after) = (self.unsigned_area(), norm.unsigned_area());
+        let tolerance = T::epsilon().sqrt() * before.max(after).max(T::one());
+        assert!(
+            (before - after).abs() <= tolerance,
+            "normalization changed the area from {:?} to {:?}",
+            before,
+            after
+        );
+        norm
+    }
+
+    /// Normalize the geometry in place using the settings given in `opts`
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>);
+
+    /// Consume the geometry and return it normalized, reusing its allocations instead of
+    /// copying it like [`normalized`] does
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::Normalized;
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// let norm = poly.into_normalized();
+    /// assert!(norm.is_normalized());
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn into_normalized(self) -> Self
+    where
+        Self: Sized,
+    {
+        self.into_normalized_with(NormalizeOptions::default())
+    }
+
+    /// Consuming version of [`normalized_with`]
+    ///
+    /// [`normalized_with`]: Normalized::normalized_with
+    fn into_normalized_with(mut self, opts: NormalizeOptions<T>) -> Self
+    where
+        Self: Sized,
+    {
+        self.normalize_mut_with(opts);
+        self
+    }
+
+    /// Return a new normalized geometry, or an error if the geometry is too broken for its
+    /// winding to be meaningful
+    ///
+    /// Unlike [`normalized`], which silently passes empty or degenerate rings through, this
+    /// rejects polygons with an empty exterior, rings with fewer than four coordinates, and
+    /// non-finite coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo_normalized2::{NormalizeError, Normalized};
+    /// let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+    ///
+    /// assert_eq!(empty.try_normalized(), Err(NormalizeError::EmptyExterior));
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn try_normalized(&self) -> Result<Self, NormalizeError>
+    where
+        Self: Clone,
+    {
+        self.try_normalized_with(NormalizeOptions::default())
+    }
+
+    /// Fallible version of [`normalized_with`], see [`try_normalized`]
+    ///
+    /// [`normalized_with`]: Normalized::normalized_with
+    /// [`try_normalized`]: Normalized::try_normalized
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError>
+    where
+        Self: Clone;
+
+    /// Return a new geometry whose ring windings are chosen by `policy` instead of a fixed
+    /// orientation
+    ///
+    /// `policy` is called with the role of each ring and its index, which is `0` for the exterior
+    /// and the position in [`Polygon::interiors`] for interior rings. Returning `Some(order)`
+    /// forces that winding on the ring, returning `None` leaves the ring untouched. No other
+    /// cleanup from [`NormalizeOptions`] is applied. Triangles are treated as a single exterior
+    /// ring, and geometries without rings are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo::algorithm::winding_order::{Winding, WindingOrder};
+    /// use geo_normalized2::{Normalized, RingRole};
+    /// let poly = polygon!(
+    ///     exterior: [
+    ///         (x: 0.0, y: 0.0),
+    ///         (x: 9.0, y: 0.0),
+    ///         (x: 9.0, y: 9.0),
+    ///         (x: 0.0, y: 9.0),
+    ///         (x: 0.0, y: 0.0),
+    ///     ],
+    ///     interiors: [
+    ///         [
+    ///             (x: 1.0, y: 1.0),
+    ///             (x: 2.0, y: 1.0),
+    ///             (x: 2.0, y: 2.0),
+    ///             (x: 1.0, y: 2.0),
+    ///             (x: 1.0, y: 1.0),
+    ///         ],
+    ///     ],
+    /// );
+    ///
+    /// // Fix the exterior but keep the holes as the legacy renderer expects them
+    /// let norm = poly.normalized_by(|role, _| match role {
+    ///     RingRole::Exterior => Some(WindingOrder::Clockwise),
+    ///     RingRole::Interior => None,
+    /// });
+    /// assert_eq!(norm.exterior().winding_order(), Some(WindingOrder::Clockwise));
+    /// assert_eq!(norm.interiors(), poly.interiors());
+    /// ```
+    fn normalized_by<F>(&self, policy: F) -> Self
+    where
+        Self: Clone,
+        F: Fn(RingRole, usize) -> Option<WindingOrder>,
+    {
+        let mut norm = self.clone();
+        norm.normalize_mut_by(&policy);
+        norm
     }
+
+    /// Set the winding of each ring in place as chosen by `policy`, see [`normalized_by`]
+    ///
+    /// [`normalized_by`]: Normalized::normalized_by
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>);
+
+    /// Check whether the geometry already follows the OGC winding rules, without building a
+    /// normalized copy.
+    ///
+    /// A polygon is normalized when its exterior ring is clockwise and all of its interior rings
+    /// are counter-clockwise. Rings that normalization leaves as they are, because they have no
+    /// winding to fix, also count as normalized: rings with fewer than three distinct points and
+    /// rings with a signed area of exactly zero. So the result of [`normalized`] is always
+    /// normalized. Collections are normalized when every member is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::Normalized;
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert!(!poly.is_normalized());
+    /// assert!(poly.normalized().is_normalized());
+    /// ```
+    ///
+    /// [`normalized`]: Normalized::normalized
+    fn is_normalized(&self) -> bool;
 }
 
-/// Return a new polygon where the exterior ring points are clockwise and interior ring points are
-/// counter-clockwise
+/* Boxes */
+
+/// Boxed geometries normalize the geometry they point to, so `Box<Polygon<T>>` can be used
+/// wherever a `Normalized` value is expected.
 ///
-fn normalized_polygon<T: num_traits::Float + CoordNum + GeoNum>(poly: &Polygon<T>) -> Polygon<T> {
-    Polygon::new(
-        LineString::from(
-            poly.exterior()
-                .points_cw()
-                .map(|x| x.0)
-                .collect::<Vec<Coordinate<T>>>(),
-        ),
-        poly.interiors()
-            .iter()
-            .map(|ring| {
-                LineString::from(
-                    ring.clone()
-                        .points_ccw()
-                        .map(|x| x.0)
-                        .collect::<Vec<Coordinate<T>>>(),
-                )
-            })
-            .collect(),
-    )
+/// References need no impl of their own: method calls such as `(&poly).normalized()` already
+/// dereference to the geometry and return an owned, normalized copy of it.
+impl<T: CoordNum, P: Normalized<T> + Clone> Normalized<T> for Box<P> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        (**self).normalize_mut_with(opts);
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        (**self).normalize_mut_by(policy);
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        (**self).try_normalized_with(opts).map(Box::new)
+    }
+
+    fn is_normalized(&self) -> bool {
+        (**self).is_normalized()
+    }
 }
 
-/** Tests */
+/* Tuples and arrays */
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use geo::polygon;
+/// Implement `Normalized` for tuples by normalizing each element independently
+macro_rules! impl_tuple {
+    ($(($($geom:ident $index:tt),*)),*) => {
+        $(
+            impl<T: CoordNum, $($geom: Normalized<T> + Clone),*> Normalized<T> for ($($geom,)*) {
+                fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+                    $(self.$index.normalize_mut_with(opts);)*
+                }
 
-    #[test]
-    fn does_not_change_good_polygon() {
-        let (good, _) = get_bad_outer_poly();
-        let norm = good.normalized();
-        assert_eq!(norm, good);
+                fn normalize_mut_by(
+                    &mut self,
+                    policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>,
+                ) {
+                    $(self.$index.normalize_mut_by(policy);)*
+                }
+
+                fn try_normalized_with(
+                    &self,
+                    opts: NormalizeOptions<T>,
+                ) -> Result<Self, NormalizeError> {
+                    Ok(($(self.$index.try_normalized_with(opts)?,)*))
+                }
+
+                fn is_normalized(&self) -> bool {
+                    $(self.$index.is_normalized())&&*
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple!((A 0, B 1), (A 0, B 1, C 2));
+
+/// Vecs normalize each element independently, like a `GeometryCollection` does for a
+/// `Vec<Geometry<T>>`
+impl<T: CoordNum, P: Normalized<T> + Clone> Normalized<T> for Vec<P> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        for geom in self.iter_mut() {
+            geom.normalize_mut_with(opts);
+        }
     }
 
-    #[test]
-    fn can_normalize_bad_outer_polygon() {
-        let (good, bad) = get_bad_outer_poly();
-        let norm = bad.normalized();
-        assert_eq!(norm, good);
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        for geom in self.iter_mut() {
+            geom.normalize_mut_by(policy);
+        }
     }
 
-    #[test]
-    fn can_normalize_good_outer_bad_inner_polygon() {
-        let (good, bad) = get_good_outer_bad_inner_poly();
-        let norm = bad.normalized();
-        assert_eq!(norm, good);
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        self.iter()
+            .map(|geom| geom.try_normalized_with(opts))
+            .collect()
     }
 
-    #[test]
-    fn can_normalize_bad_outer_bad_inner_polygon() {
-        let (good, bad) = get_bad_outer_bad_inner_poly();
-        let norm = bad.normalized();
-        assert_eq!(norm, good);
+    fn is_normalized(&self) -> bool {
+        self.iter().all(|geom| geom.is_normalized())
     }
+}
 
-    #[test]
-    fn can_normalize_bad_outer_good_inner_polygon() {
-        let (good, bad) = get_bad_outer_good_inner_poly();
-        let norm = bad.normalized();
-        assert_eq!(norm, good);
+/// Arrays normalize each element independently
+impl<T: CoordNum, P: Normalized<T> + Clone, const N: usize> Normalized<T> for [P; N] {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        for geom in self.iter_mut() {
+            geom.normalize_mut_with(opts);
+        }
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        for geom in self.iter_mut() {
+            geom.normalize_mut_by(policy);
+        }
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        let mut norm = self.clone();
+        for geom in norm.iter_mut() {
+            *geom = geom.try_normalized_with(opts)?;
+        }
+        Ok(norm)
+    }
+
+    fn is_normalized(&self) -> bool {
+        self.iter().all(|geom| geom.is_normalized())
+    }
+}
+
+/* Geometry Collections */
+
+impl<T: CoordNum> Normalized<T> for GeometryCollection<T> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        for geom in self.0.iter_mut() {
+            geom.normalize_mut_with(opts);
+        }
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        for geom in self.0.iter_mut() {
+            geom.normalize_mut_by(policy);
+        }
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        Ok(GeometryCollection(
+            self.0
+                .iter()
+                .map(|geom| geom.try_normalized_with(opts))
+                .collect::<Result<Vec<Geometry<T>>, NormalizeError>>()?,
+        ))
+    }
+
+    fn is_normalized(&self) -> bool {
+        self.0.iter().all(|geom| geom.is_normalized())
+    }
+}
+
+/* Geometries */
+
+/// Polygons, MultiPolygons, Triangles and Rects are normalized, and nested GeometryCollections are
+/// normalized recursively. All other geometry variants are left unchanged.
+impl<T: CoordNum> Normalized<T> for Geometry<T> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        match self {
+            Geometry::Polygon(p) => p.normalize_mut_with(opts),
+            Geometry::MultiPolygon(mp) => mp.normalize_mut_with(opts),
+            Geometry::GeometryCollection(gc) => gc.normalize_mut_with(opts),
+            Geometry::Triangle(t) => t.normalize_mut_with(opts),
+            Geometry::Rect(r) => r.normalize_mut_with(opts),
+            Geometry::Line(l) => l.normalize_mut_with(opts),
+            _ => {}
+        }
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        match self {
+            Geometry::Polygon(p) => p.normalize_mut_by(policy),
+            Geometry::MultiPolygon(mp) => mp.normalize_mut_by(policy),
+            Geometry::GeometryCollection(gc) => gc.normalize_mut_by(policy),
+            Geometry::Triangle(t) => t.normalize_mut_by(policy),
+            Geometry::Rect(r) => r.normalize_mut_by(policy),
+            Geometry::Line(l) => l.normalize_mut_by(policy),
+            _ => {}
+        }
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        match self {
+            Geometry::Polygon(p) => p.try_normalized_with(opts).map(Geometry::Polygon),
+            Geometry::MultiPolygon(mp) => mp.try_normalized_with(opts).map(Geometry::MultiPolygon),
+            Geometry::GeometryCollection(gc) => gc
+                .try_normalized_with(opts)
+                .map(Geometry::GeometryCollection),
+            Geometry::Triangle(t) => t.try_normalized_with(opts).map(Geometry::Triangle),
+            Geometry::Rect(r) => r.try_normalized_with(opts).map(Geometry::Rect),
+            Geometry::Line(l) => l.try_normalized_with(opts).map(Geometry::Line),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    fn is_normalized(&self) -> bool {
+        match self {
+            Geometry::Polygon(p) => p.is_normalized(),
+            Geometry::MultiPolygon(mp) => mp.is_normalized(),
+            Geometry::GeometryCollection(gc) => gc.is_normalized(),
+            Geometry::Triangle(t) => t.is_normalized(),
+            Geometry::Rect(r) => r.is_normalized(),
+            Geometry::Line(l) => l.is_normalized(),
+            _ => true,
+        }
+    }
+}
+
+/* Non-areal geometries */
+
+/// Implement `Normalized` as an intentional no-op for geometries without rings, which have no
+/// winding requirement. This keeps the trait usable on every geo geometry type in generic code.
+macro_rules! impl_pass_through {
+    ($($geom:ident),*) => {
+        $(
+            /// Intentional no-op, this geometry has no rings to wind
+            impl<T: CoordNum> Normalized<T> for $geom<T> {
+                fn normalize_mut_with(&mut self, _opts: NormalizeOptions<T>) {}
+
+                fn normalize_mut_by(
+                    &mut self,
+                    _policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>,
+                ) {
+                }
+
+                fn try_normalized_with(
+                    &self,
+                    _opts: NormalizeOptions<T>,
+                ) -> Result<Self, NormalizeError> {
+                    Ok(self.clone())
+                }
+
+                fn is_normalized(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_pass_through!(Point, MultiPoint, Line, LineString, MultiLineString);
+
+/* Triangles and Rects */
+
+impl<T: CoordNum> Normalized<T> for Triangle<T> {
+    /// Order the vertices to follow the exterior winding of the requested orientation, so
+    /// clockwise by default. Degenerate triangles are left unchanged.
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        let wanted = opts.y_axis.apply(opts.orientation.exterior_winding());
+        if winding::triangle_winding(self).is_some_and(|winding| Some(winding) != wanted) {
+            *self = Triangle::unchecked_winding(self.v1(), self.v3(), self.v2());
+        }
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        if let Some(wanted) = policy(RingRole::Exterior, 0) {
+            if winding::triangle_winding(self).is_some_and(|winding| winding != wanted) {
+                *self = Triangle::unchecked_winding(self.v1(), self.v3(), self.v2());
+            }
+        }
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        if let Some(point_index) = self
+            .to_array()
+            .iter()
+            .position(|c| !is_finite(c.x) || !is_finite(c.y))
+        {
+            return Err(NormalizeError::NonFinite {
+                ring_index: 0,
+                point_index,
+            });
+        }
+        check_range(&self.to_array(), 0, opts)?;
+        Ok(self.normalized_with(opts))
+    }
+
+    fn is_normalized(&self) -> bool {
+        winding::triangle_matches(self, Orientation::Ogc.exterior_winding())
+    }
+}
+
+/// A rect only stores its corners, so it has no winding to fix. This is an intentional no-op
+/// that keeps the trait usable on every areal geometry.
+impl<T: CoordNum> Normalized<T> for Rect<T> {
+    fn normalize_mut_with(&mut self, _opts: NormalizeOptions<T>) {}
+
+    fn normalize_mut_by(&mut self, _policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {}
+
+    fn try_normalized_with(&self, _opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        Ok(*self)
+    }
+
+    fn is_normalized(&self) -> bool {
+        true
+    }
+}
+
+/* Polygons */
+
+impl<T: CoordNum> Normalized<T> for MultiPolygon<T> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        normalize_members(&mut self.0, opts, |polys| {
+            for poly in polys {
+                poly.normalize_mut_with(opts);
+            }
+            Ok::<(), Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {});
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        for poly in self.0.iter_mut() {
+            poly.normalize_mut_by(policy);
+        }
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        let mut polys = self.0.clone();
+        normalize_members(&mut polys, opts, |polys| {
+            polys.iter_mut().try_for_each(|poly| {
+                check_polygon(poly, opts)?;
+                poly.normalize_mut_with(opts);
+                Ok(())
+            })
+        })?;
+        Ok(MultiPolygon(polys))
+    }
+
+    fn is_normalized(&self) -> bool {
+        self.0.iter().all(|poly| poly.is_normalized())
+    }
+}
+
+/// Normalize the members of a multipolygon with `normalize`, applying the options that act on
+/// the members as a whole: empty members are dropped before `normalize` runs, and the members
+/// are sorted after it succeeds
+pub(crate) fn normalize_members<T: CoordNum, E>(
+    polys: &mut Vec<Polygon<T>>,
+    opts: NormalizeOptions<T>,
+    normalize: impl FnOnce(&mut [Polygon<T>]) -> Result<(), E>,
+) -> Result<(), E> {
+    if opts.drop_empty {
+        polys.retain(|poly| !poly.exterior().0.is_empty());
+    }
+    normalize(polys)?;
+    if opts.sort_polygons {
+        polys.sort_by(ring::cmp_polygons);
+    }
+    Ok(())
+}
+
+impl<T: CoordNum> Normalized<T> for Polygon<T> {
+    fn normalize_mut_with(&mut self, opts: NormalizeOptions<T>) {
+        normalize_polygon_mut(self, opts, None, None)
+    }
+
+    fn normalize_mut_by(&mut self, policy: &dyn Fn(RingRole, usize) -> Option<WindingOrder>) {
+        if let Some(winding) = policy(RingRole::Exterior, 0) {
+            self.exterior_mut(|ring| {
+                ring::wind(ring, winding);
+            });
+        }
+        self.interiors_mut(|rings| {
+            for (i, ring) in rings.iter_mut().enumerate() {
+                if let Some(winding) = policy(RingRole::Interior, i) {
+                    ring::wind(ring, winding);
+                }
+            }
+        });
+    }
+
+    fn try_normalized_with(&self, opts: NormalizeOptions<T>) -> Result<Self, NormalizeError> {
+        check_polygon(self, opts)?;
+        Ok(self.normalized_with(opts))
+    }
+
+    fn is_normalized(&self) -> bool {
+        self.matches(Orientation::Ogc)
+    }
+}
+
+/// Reverse, in place, the rings of a polygon whose winding is wrong so that the exterior and
+/// interior ring points follow the requested orientation, recording what was changed in
+/// `report` and the unsigned area of the result in `area` when they are given
+///
+/// A polygon with an empty exterior is left exactly as it is.
+fn normalize_polygon_mut<T: CoordNum>(
+    poly: &mut Polygon<T>,
+    opts: NormalizeOptions<T>,
+    mut report: Option<&mut NormalizeReport>,
+    area: Option<&mut T>,
+) {
+    if poly.exterior().0.is_empty() {
+        return;
+    }
+    let measure = area.is_some();
+    let half = |twice_area: T| ring::abs(twice_area / (T::one() + T::one()));
+    let mut exterior_area = T::zero();
+    let exterior = opts.y_axis.apply(opts.orientation.exterior_winding());
+    poly.exterior_mut(|ring| {
+        let mut twice_area = T::zero();
+        let changes = normalize_ring_mut(ring, exterior, opts, measure.then_some(&mut twice_area));
+        exterior_area = half(twice_area);
+        #[cfg(feature = "log")]
+        changes.log(RingRole::Exterior, 0);
+        if let Some(report) = report.as_deref_mut() {
+            report.exterior_reversed = changes.reversed;
+            report.add(changes);
+        }
+    });
+    // Only look up the winding the exterior ended up with when the interiors depend on it
+    let interior = match opts.y_axis.apply(opts.orientation.interior_winding()) {
+        Some(winding) => Some(winding),
+        None => opts
+            .orientation
+            .interior_winding_within(exterior_winding(poly)),
+    };
+    let mut interiors_area = T::zero();
+    poly.interiors_mut(|rings| {
+        for (i, ring) in rings.iter_mut().enumerate() {
+            let mut twice_area = T::zero();
+            let changes =
+                normalize_ring_mut(ring, interior, opts, measure.then_some(&mut twice_area));
+            interiors_area = interiors_area + half(twice_area);
+            #[cfg(feature = "log")]
+            changes.log(RingRole::Interior, i);
+            if let Some(report) = report.as_deref_mut() {
+                if changes.reversed {
+                    report.reversed_interiors.push(i);
+                }
+                report.add(changes);
+            }
+        }
+        if opts.sort_interiors {
+            rings.sort_by(ring::cmp_rings);
+        }
+    });
+    let drop = opts.drop_degenerate_interiors && poly.interiors().iter().any(ring::has_zero_area);
+    let dedupe = opts.dedupe_interiors && poly.interiors().len() > 1;
+    let copies = opts.drop_exterior_copies
+        && poly
+            .interiors()
+            .iter()
+            .any(|ring| ring::is_same_boundary(poly.exterior(), ring));
+    if drop || dedupe || copies {
+        let empty = Polygon::new(LineString::new(Vec::new()), Vec::new());
+        let (exterior, mut interiors) = core::mem::replace(poly, empty).into_inner();
+        if drop {
+            let before = interiors.len();
+            interiors.retain(|ring| !ring::has_zero_area(ring));
+            if let Some(report) = report.as_deref_mut() {
+                report.dropped_interiors += before - interiors.len();
+            }
+        }
+        if dedupe {
+            let before = interiors.len();
+            ring::dedupe_rings(&mut interiors);
+            if let Some(report) = report.as_deref_mut() {
+                report.deduped_interiors += before - interiors.len();
+            }
+        }
+        if copies {
+            let before = interiors.len();
+            interiors.retain(|ring| !ring::is_same_boundary(&exterior, ring));
+            if let Some(report) = report {
+                report.dropped_exterior_copies += before - interiors.len();
+            }
+        }
+        if measure {
+            interiors_area = interiors.iter().fold(T::zero(), |sum, ring| {
+                sum + half(ring::twice_signed_area(ring))
+            });
+        }
+        *poly = Polygon::new(exterior, interiors);
+    }
+    if let Some(area) = area {
+        *area = ring::abs(exterior_area - interiors_area);
+    }
+}
+
+/// Clean up a single ring as requested by `opts`, and reverse it if it is not wound in the
+/// `winding` order, when one is given. The doubled signed area of the cleaned up ring is stored
+/// in `twice_area` when it is given, and then reused to decide the reversal.
+fn normalize_ring_mut<T: CoordNum>(
+    ring: &mut LineString<T>,
+    winding: Option<WindingOrder>,
+    opts: NormalizeOptions<T>,
+    twice_area: Option<&mut T>,
+) -> RingChanges {
+    let mut changes = RingChanges::default();
+    if let Some(decimals) = opts.round_decimals {
+        ring::round_coords(ring, decimals);
+    }
+    if opts.dedupe_consecutive {
+        let len = ring.0.len();
+        ring.0.dedup();
+        changes.deduped_points = len - ring.0.len();
+    }
+    changes.closed = !ring.is_closed();
+    ring.close();
+    if opts.remove_spikes {
+        let len = ring.0.len();
+        ring::remove_spikes(ring, opts.collinear_tolerance);
+        changes.spike_points = len - ring.0.len();
+    }
+    if opts.remove_collinear {
+        let len = ring.0.len();
+        ring::remove_collinear(ring, opts.collinear_tolerance);
+        changes.collinear_points = len - ring.0.len();
+    }
+    let twice_area = twice_area.map(|twice_area| {
+        *twice_area = ring::twice_signed_area(ring);
+        *twice_area
+    });
+    if let Some(winding) = winding {
+        changes.reversed = match twice_area {
+            Some(twice_area) => ring::wind_with_area(ring, winding, twice_area),
+            None => ring::wind(ring, winding),
+        };
+    }
+    if opts.canonical_start {
+        ring::rotate_to_min_start(ring);
+    }
+    changes
+}
+
+/// Check that every ring of a polygon has enough finite coordinates to have a winding
+fn check_polygon<T: CoordNum>(
+    poly: &Polygon<T>,
+    opts: NormalizeOptions<T>,
+) -> Result<(), NormalizeError> {
+    if let Some(max_vertices) = opts.max_vertices {
+        let count = poly.exterior().0.len()
+            + poly
+                .interiors()
+                .iter()
+                .map(|ring| ring.0.len())
+                .sum::<usize>();
+        if count > max_vertices {
+            return Err(NormalizeError::TooManyVertices { count });
+        }
+    }
+    if poly.exterior().0.is_empty() {
+        return Err(NormalizeError::EmptyExterior);
+    }
+    for (ring_index, ring) in core::iter::once(poly.exterior())
+        .chain(poly.interiors())
+        .enumerate()
+    {
+        if ring.0.len() < 4 {
+            return Err(NormalizeError::DegenerateRing { ring_index });
+        }
+        if let Some(point_index) = ring
+            .0
+            .iter()
+            .position(|c| !is_finite(c.x) || !is_finite(c.y))
+        {
+            return Err(NormalizeError::NonFinite {
+                ring_index,
+                point_index,
+            });
+        }
+        check_range(&ring.0, ring_index, opts)?;
+        if opts.reject_zero_area && ring::has_zero_area(ring) {
+            return Err(NormalizeError::ZeroArea { ring_index });
+        }
+    }
+    Ok(())
+}
+
+/// Check that every coordinate of a ring lies within `opts.coordinate_range`, when it is set
+fn check_range<T: CoordNum>(
+    coords: &[Coord<T>],
+    ring_index: usize,
+    opts: NormalizeOptions<T>,
+) -> Result<(), NormalizeError> {
+    let Some((min, max)) = opts.coordinate_range else {
+        return Ok(());
+    };
+    match coords
+        .iter()
+        .position(|c| c.x < min.x || c.x > max.x || c.y < min.y || c.y > max.y)
+    {
+        Some(point_index) => Err(NormalizeError::OutOfRange {
+            ring_index,
+            point_index,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Whether a value is neither `NaN` nor infinite, which is always the case for integers. Both
+/// `NaN - NaN` and `inf - inf` are `NaN`, which is the only value not equal to itself.
+#[allow(clippy::eq_op)]
+fn is_finite<T: CoordNum>(value: T) -> bool {
+    let zero = value - value;
+    zero == zero
+}
+
+/* Tests */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::algorithm::winding_order::Winding;
+    use geo::polygon;
+    use geo::Area;
+
+    #[test]
+    fn does_not_change_good_polygon() {
+        let (good, _) = get_bad_outer_poly();
+        let norm = good.normalized();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn can_normalize_bad_outer_polygon() {
+        let (good, bad) = get_bad_outer_poly();
+        let norm = bad.normalized();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn can_normalize_good_outer_bad_inner_polygon() {
+        let (good, bad) = get_good_outer_bad_inner_poly();
+        let norm = bad.normalized();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn can_normalize_bad_outer_bad_inner_polygon() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let norm = bad.normalized();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn can_normalize_bad_outer_good_inner_polygon() {
+        let (good, bad) = get_bad_outer_good_inner_poly();
+        let norm = bad.normalized();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn can_normalize_polygon_in_place() {
+        let (good, mut bad) = get_bad_outer_bad_inner_poly();
+        bad.normalize_mut();
+        assert_eq!(bad, good);
+    }
+
+    #[test]
+    fn normalize_mut_does_not_change_good_polygon() {
+        let (good, _) = get_bad_outer_good_inner_poly();
+        let mut norm = good.clone();
+        norm.normalize_mut();
+        assert_eq!(norm, good);
+    }
+
+    #[test]
+    fn detects_normalized_polygons() {
+        let (good, bad) = get_bad_outer_poly();
+        assert!(good.is_normalized());
+        assert!(!bad.is_normalized());
+
+        let (good, bad) = get_good_outer_bad_inner_poly();
+        assert!(good.is_normalized());
+        assert!(!bad.is_normalized());
+    }
+
+    #[test]
+    fn detects_normalized_collections() {
+        let (good, bad) = get_bad_outer_good_inner_poly();
+        assert!(MultiPolygon(vec![good.clone(), good.clone()]).is_normalized());
+        assert!(!MultiPolygon(vec![good.clone(), bad.clone()]).is_normalized());
+
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(good.clone()),
+            Geometry::Point(geo::point!(x: 1.0, y: 1.0)),
+        ]);
+        assert!(gc.is_normalized());
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(good.clone()),
+            Geometry::MultiPolygon(MultiPolygon(vec![bad])),
+        ]);
+        assert!(!gc.is_normalized());
+        assert!(gc.normalized().is_normalized());
+    }
+
+    #[test]
+    fn can_normalize_geometry() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(
+            Geometry::Polygon(bad.clone()).normalized(),
+            Geometry::Polygon(good.clone())
+        );
+        assert_eq!(
+            Geometry::MultiPolygon(MultiPolygon(vec![bad.clone()])).normalized(),
+            Geometry::MultiPolygon(MultiPolygon(vec![good]))
+        );
+
+        let line = Geometry::LineString(bad.exterior().clone());
+        assert_eq!(line.normalized(), line);
+    }
+
+    #[test]
+    fn can_normalize_to_geojson_orientation() {
+        let (ogc, bad) = get_bad_outer_good_inner_poly();
+        let opts = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        };
+        let norm = bad.normalized_with(opts);
+        assert!(norm.exterior().is_ccw());
+        assert!(norm.interiors().iter().all(|ring| ring.is_cw()));
+        assert_eq!(ogc.normalized_with(opts), norm);
+        assert_eq!(norm.normalized(), ogc);
+    }
+
+    #[test]
+    fn can_process_every_geometry_variant() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let others = vec![
+            Geometry::Point(geo::point!(x: 1.0, y: 1.0)),
+            Geometry::Line(geo::Line::new((0.0, 0.0), (1.0, 1.0))),
+            Geometry::LineString(bad.exterior().clone()),
+            Geometry::MultiPoint(geo::MultiPoint::from(vec![(0.0, 0.0), (1.0, 1.0)])),
+            Geometry::MultiLineString(geo::MultiLineString(vec![bad.exterior().clone()])),
+            Geometry::Rect(geo::Rect::new((0.0, 0.0), (1.0, 1.0))),
+            Geometry::Triangle(
+                geo::Triangle::from([(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]).normalized(),
+            ),
+        ];
+        let mut input = others.clone();
+        input.push(Geometry::Polygon(bad.clone()));
+        input.push(Geometry::MultiPolygon(MultiPolygon(vec![bad])));
+        let mut expected = others;
+        expected.push(Geometry::Polygon(good.clone()));
+        expected.push(Geometry::MultiPolygon(MultiPolygon(vec![good])));
+
+        let norm = GeometryCollection(input).normalized();
+        assert_eq!(norm, GeometryCollection(expected));
+    }
+
+    #[test]
+    fn try_normalized_accepts_valid_input() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(bad.try_normalized(), Ok(good.clone()));
+
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(bad.clone()),
+            Geometry::MultiPolygon(MultiPolygon(vec![bad])),
+        ]);
+        assert_eq!(gc.try_normalized(), Ok(gc.normalized()));
+    }
+
+    #[test]
+    fn try_normalized_rejects_degenerate_input() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.try_normalized(), Err(NormalizeError::EmptyExterior));
+
+        let (good, _) = get_good_outer_bad_inner_poly();
+        let mut degenerate = good.clone();
+        degenerate.interiors_push(vec![(1.0, 1.0), (2.0, 2.0)]);
+        assert_eq!(
+            degenerate.try_normalized(),
+            Err(NormalizeError::DegenerateRing { ring_index: 2 })
+        );
+
+        let mut non_finite = good.clone();
+        non_finite.exterior_mut(|ring| ring.0[1].y = f64::NAN);
+        assert_eq!(
+            MultiPolygon(vec![good, non_finite]).try_normalized(),
+            Err(NormalizeError::NonFinite {
+                ring_index: 0,
+                point_index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn can_normalize_polygon_with_many_holes() {
+        let hole = |i: usize| {
+            let x = (i % 20) as f64 * 2.0 + 1.0;
+            let y = (i / 20) as f64 * 2.0 + 1.0;
+            LineString::from(vec![(x, y), (x, y + 1.0), (x + 1.0, y + 1.0), (x + 1.0, y)])
+        };
+        let exterior = LineString::from(vec![(0.0, 0.0), (50.0, 0.0), (50.0, 50.0), (0.0, 50.0)]);
+        let bad = Polygon::new(exterior, (0..300).map(hole).collect());
+
+        let norm = bad.normalized();
+        assert!(norm.is_normalized());
+        assert_eq!(norm.interiors().len(), 300);
+        for (ring, original) in norm.interiors().iter().zip(bad.interiors()) {
+            assert!(ring.0.iter().eq(original.0.iter().rev()));
+        }
+    }
+
+    #[test]
+    fn can_canonicalize_ring_start() {
+        let opts = NormalizeOptions {
+            canonical_start: true,
+            ..Default::default()
+        };
+        let (good, _) = get_bad_outer_poly();
+        let rotated = polygon![
+        (x: 4.0, y: 4.0),
+        (x: 1.0, y: 4.0),
+        (x: 1.0, y: 1.0),
+        (x: 4.0, y: 1.0),
+        (x: 4.0, y: 4.0),
+        ];
+        assert_ne!(rotated.normalized(), good);
+        assert_eq!(rotated.normalized_with(opts), good);
+        assert_eq!(good.normalized_with(opts), good);
+
+        let (good, bad) = get_good_outer_bad_inner_poly();
+        let mut shifted = bad.clone();
+        shifted.interiors_mut(|rings| {
+            rings[0] = LineString::from(vec![(20., 20.), (20., 10.), (10., 10.), (10., 20.)])
+        });
+        assert_eq!(shifted.normalized_with(opts), good);
+    }
+
+    #[test]
+    fn closes_open_rings() {
+        let open = LineString::from(vec![(0., 0.), (50., 0.), (50., 50.), (0., 50.)]);
+        let hole = LineString::from(vec![(10., 10.), (10., 20.), (20., 20.), (20., 10.)]);
+        let norm = Polygon::new(open, vec![hole]).normalized();
+        assert!(norm.exterior().is_closed());
+        assert_eq!(norm.exterior().0.len(), 5);
+        assert!(norm.interiors()[0].is_closed());
+        assert_eq!(norm.interiors()[0].0.len(), 5);
+    }
+
+    #[test]
+    fn does_not_duplicate_closing_point() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let norm = bad.normalized();
+        assert_eq!(norm.exterior().0.len(), 5);
+        assert_eq!(norm.interiors()[0].0.len(), 5);
+        assert_eq!(norm.normalized(), good);
+    }
+
+    #[test]
+    fn can_drop_consecutive_duplicates() {
+        let opts = NormalizeOptions {
+            dedupe_consecutive: true,
+            ..Default::default()
+        };
+        let (good, _) = get_bad_outer_poly();
+        let noisy = Polygon::new(
+            LineString::from(vec![
+                (1.0, 1.0),
+                (1.0, 1.0),
+                (4.0, 1.0),
+                (4.0, 4.0),
+                (4.0, 4.0),
+                (4.0, 4.0),
+                (1.0, 4.0),
+                (1.0, 1.0),
+                (1.0, 1.0),
+            ]),
+            vec![],
+        );
+        assert_eq!(noisy.normalized_with(opts), good);
+        assert_eq!(noisy.normalized().exterior().0.len(), 9);
+    }
+
+    #[test]
+    fn can_remove_collinear_points() {
+        let opts = NormalizeOptions {
+            remove_collinear: true,
+            ..Default::default()
+        };
+        let (good, _) = get_bad_outer_poly();
+        let redundant = Polygon::new(
+            LineString::from(vec![
+                (2.0, 1.0),
+                (3.0, 1.0),
+                (4.0, 1.0),
+                (4.0, 2.5),
+                (4.0, 4.0),
+                (1.0, 4.0),
+                (1.0, 1.0),
+            ]),
+            vec![],
+        );
+        let norm = redundant.normalized_with(opts);
+        assert_eq!(norm.exterior().0.len(), 5);
+        assert!(norm.is_normalized());
+        let canonical = NormalizeOptions {
+            canonical_start: true,
+            ..opts
+        };
+        assert_eq!(redundant.normalized_with(canonical), good);
+    }
+
+    #[test]
+    fn collinear_tolerance_is_configurable() {
+        let nearly = Polygon::new(
+            LineString::from(vec![
+                (1.0, 1.0),
+                (1.0, 4.0),
+                (2.5, 4.001),
+                (4.0, 4.0),
+                (4.0, 1.0),
+            ]),
+            vec![],
+        );
+        let exact = NormalizeOptions {
+            remove_collinear: true,
+            ..Default::default()
+        };
+        assert_eq!(nearly.normalized_with(exact).exterior().0.len(), 6);
+        let tolerant = NormalizeOptions {
+            collinear_tolerance: 0.01,
+            ..exact
+        };
+        assert_eq!(nearly.normalized_with(tolerant).exterior().0.len(), 5);
+    }
+
+    #[test]
+    fn can_sort_interior_rings() {
+        let opts = NormalizeOptions {
+            sort_interiors: true,
+            ..Default::default()
+        };
+        let exterior = LineString::from(vec![(0., 0.), (0., 50.), (50., 50.), (50., 0.)]);
+        let low = LineString::from(vec![
+            (10., 10.),
+            (20., 10.),
+            (20., 20.),
+            (10., 20.),
+            (10., 10.),
+        ]);
+        let high = LineString::from(vec![(30., 10.), (40., 10.), (40., 20.), (30., 20.)]);
+        let small = LineString::from(vec![(10., 30.), (20., 30.), (10., 40.), (10., 30.)]);
+        let big = LineString::from(vec![(10., 30.), (20., 30.), (20., 40.), (10., 40.)]);
+
+        let a = Polygon::new(
+            exterior.clone(),
+            vec![high.clone(), big.clone(), low.clone(), small.clone()],
+        );
+        let b = Polygon::new(
+            exterior.clone(),
+            vec![small.clone(), low.clone(), big, high],
+        );
+        assert_ne!(a.normalized(), b.normalized());
+        let norm = a.normalized_with(opts);
+        assert_eq!(norm, b.normalized_with(opts));
+        assert_eq!(norm.interiors()[0], low);
+        assert_eq!(norm.interiors()[1], small);
+    }
+
+    #[test]
+    fn sorting_interiors_with_nan_coordinates_does_not_panic() {
+        use geo::MapCoords;
+
+        let (square, _) = get_bad_outer_poly();
+        let hole = square.exterior();
+        // The same intransitive pattern as for sorting multipolygon members
+        let nan = hole.map_coords(|c| geo::coord! { x: f64::NAN, y: c.y - 10. });
+        let interiors = (0..40)
+            .map(|i| {
+                if i % 3 == 0 {
+                    nan.clone()
+                } else {
+                    let i = f64::from(i);
+                    hole.map_coords(|c| geo::coord! { x: c.x + i, y: c.y - i })
+                }
+            })
+            .collect();
+        let exterior = LineString::from(vec![(-99., -99.), (-99., 99.), (99., 99.), (99., -99.)]);
+        let opts = NormalizeOptions::builder().sort_interiors(true).build();
+        let norm = Polygon::new(exterior, interiors).normalized_with(opts);
+        let first_nan = norm.interiors().iter().position(|r| r.0[0].x.is_nan());
+        assert_eq!(first_nan, Some(26));
+        assert!(norm.interiors()[..26]
+            .windows(2)
+            .all(|w| w[0].0[0].x < w[1].0[0].x));
+    }
+
+    #[test]
+    fn can_drop_degenerate_interiors() {
+        let opts = NormalizeOptions {
+            drop_degenerate_interiors: true,
+            ..Default::default()
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let collinear = LineString::from(vec![(30., 30.), (35., 35.), (40., 40.), (30., 30.)]);
+        let mut with_line = bad.clone();
+        with_line.interiors_push(collinear.clone());
+
+        assert_eq!(with_line.normalized_with(opts), good);
+        assert_eq!(with_line.normalized().interiors().len(), 2);
+
+        let line_exterior = Polygon::new(collinear.clone(), vec![collinear]);
+        let norm = line_exterior.normalized_with(opts);
+        assert_eq!(norm.exterior(), line_exterior.exterior());
+        assert!(norm.interiors().is_empty());
+    }
+
+    #[test]
+    fn interiors_equal_to_the_exterior_are_reported_or_dropped() {
+        let (good, _) = get_bad_outer_poly();
+        let mut copied = good.clone();
+        copied.interiors_push(good.exterior().clone());
+        let violations = vec![OgcViolation::InteriorEqualsExterior { index: 0 }];
+        let norm = copied.normalized();
+        assert_eq!(norm.interiors().len(), 1);
+        assert_eq!(validate_ogc(&norm), violations);
+        assert_eq!(normalize_and_validate(&copied).1, violations);
+        assert_eq!(
+            validate_ogc(&copied),
+            vec![
+                OgcViolation::WrongWinding {
+                    role: RingRole::Interior,
+                    index: 0,
+                    expected: WindingOrder::CounterClockwise,
+                },
+                OgcViolation::InteriorEqualsExterior { index: 0 },
+            ]
+        );
+
+        let opts = NormalizeOptions::builder()
+            .drop_exterior_copies(true)
+            .canonical_start(true)
+            .build();
+        assert_eq!(
+            copied.try_normalized_with(opts),
+            Ok(good.normalized_with(opts))
+        );
+        let (norm, report) = normalize_report_with(&copied, opts);
+        assert!(norm.interiors().is_empty());
+        assert_eq!(report.dropped_exterior_copies, 1);
+
+        let (_, bad) = get_good_outer_bad_inner_poly();
+        let mut rotated = bad.interiors()[0].0.clone();
+        rotated.pop();
+        rotated.rotate_left(2);
+        let mut holed = bad.clone();
+        holed.interiors_push(LineString::from(rotated));
+        assert_eq!(validate_ogc(&bad.normalized()), vec![]);
+        assert_eq!(holed.normalized_with(opts).interiors().len(), 2);
+        assert_eq!(
+            bad.normalized_with(opts),
+            bad.normalized_with(NormalizeOptions {
+                drop_exterior_copies: false,
+                ..opts
+            })
+        );
+    }
+
+    #[test]
+    fn can_round_coordinates() {
+        let opts = NormalizeOptions {
+            round_decimals: Some(3),
+            dedupe_consecutive: true,
+            ..Default::default()
+        };
+        let noisy = polygon![
+            (x: 0.1000000001, y: 0.0999999998),
+            (x: 0.0999999998, y: 0.1000000001),
+            (x: 4.0004, y: 0.1),
+            (x: 4.0, y: 3.9996),
+            (x: 0.1, y: 0.1),
+        ];
+        let expected = polygon![
+            (x: 0.1, y: 0.1),
+            (x: 4.0, y: 4.0),
+            (x: 4.0, y: 0.1),
+            (x: 0.1, y: 0.1),
+        ];
+        assert_eq!(noisy.normalized_with(opts), expected);
+
+        let ints: Polygon<i64> = polygon![
+            (x: 1, y: 1),
+            (x: 1, y: 4),
+            (x: 4, y: 4),
+            (x: 4, y: 1),
+        ];
+        let opts = NormalizeOptions {
+            round_decimals: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(ints.normalized_with(opts), ints);
+    }
+
+    #[test]
+    fn builder_sets_every_option() {
+        let opts = NormalizeOptions::builder()
+            .orientation(Orientation::GeoJson)
+            .canonical_start(true)
+            .dedupe_consecutive(true)
+            .remove_collinear(true)
+            .remove_spikes(true)
+            .collinear_tolerance(0.5)
+            .sort_interiors(true)
+            .sort_polygons(true)
+            .drop_empty(true)
+            .drop_degenerate_interiors(true)
+            .dedupe_interiors(true)
+            .drop_exterior_copies(true)
+            .round_decimals(Some(2))
+            .reject_zero_area(true)
+            .coordinate_range(Some(((-180., -90.).into(), (180., 90.).into())))
+            .max_vertices(Some(1000))
+            .y_axis(YAxis::Down)
+            .build();
+        assert_eq!(
+            opts,
+            NormalizeOptions {
+                orientation: Orientation::GeoJson,
+                canonical_start: true,
+                dedupe_consecutive: true,
+                remove_collinear: true,
+                remove_spikes: true,
+                collinear_tolerance: 0.5,
+                sort_interiors: true,
+                sort_polygons: true,
+                drop_empty: true,
+                drop_degenerate_interiors: true,
+                dedupe_interiors: true,
+                drop_exterior_copies: true,
+                round_decimals: Some(2),
+                reject_zero_area: true,
+                coordinate_range: Some(((-180., -90.).into(), (180., 90.).into())),
+                max_vertices: Some(1000),
+                y_axis: YAxis::Down,
+            }
+        );
+        assert_eq!(
+            NormalizeOptions::<f64>::builder().build(),
+            NormalizeOptions::default()
+        );
+    }
+
+    #[test]
+    fn empty_multi_polygon_members_are_kept_or_dropped() {
+        let (good, bad) = get_bad_outer_poly();
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        let mp = MultiPolygon(vec![empty.clone(), bad.clone(), empty.clone()]);
+
+        let kept = mp.normalized();
+        assert_eq!(kept, MultiPolygon(vec![empty.clone(), good.clone(), empty]));
+        assert_eq!(mp.try_normalized(), Err(NormalizeError::EmptyExterior));
+
+        let opts = NormalizeOptions::builder().drop_empty(true).build();
+        let dropped = MultiPolygon(vec![good]);
+        assert_eq!(mp.normalized_with(opts), dropped);
+        assert_eq!(mp.try_normalized_with(opts), Ok(dropped.clone()));
+        assert_eq!(dropped.normalized_with(opts), dropped);
+        #[cfg(feature = "rayon")]
+        assert_eq!(mp.normalized_par_with(opts), dropped);
+    }
+
+    #[test]
+    fn y_down_flips_the_winding_in_math_terms() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let screen = NormalizeOptions::builder().y_axis(YAxis::Down).build();
+        // Clockwise on a y-down screen is counter-clockwise in y-up terms, a positive area
+        let norm = good.normalized_with(screen);
+        assert_eq!(norm, bad);
+        assert!(ring_signed_area(norm.exterior()) > 0.);
+        assert!(ring_signed_area(&norm.interiors()[0]) < 0.);
+        assert_eq!(bad.normalized_with(screen), bad);
+
+        let geojson = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..screen
+        };
+        assert_eq!(bad.normalized_with(geojson), good);
+        let opposite = NormalizeOptions {
+            orientation: Orientation::OppositeInterior,
+            ..screen
+        };
+        assert_eq!(good.normalized_with(opposite), good);
+
+        let tri = Triangle::new((0., 0.).into(), (4., 0.).into(), (0., 4.).into());
+        assert_eq!(tri.normalized_with(screen), tri);
+        assert_ne!(tri.normalized(), tri);
+    }
+
+    #[test]
+    fn can_dedupe_interiors() {
+        let poly = polygon!(
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 0., y: 50.),
+                (x: 50., y: 50.),
+                (x: 50., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 10., y: 10.),
+                    (x: 20., y: 10.),
+                    (x: 20., y: 20.),
+                    (x: 10., y: 20.),
+                ],
+                [
+                    (x: 30., y: 30.),
+                    (x: 40., y: 30.),
+                    (x: 40., y: 40.),
+                    (x: 30., y: 40.),
+                ],
+                [
+                    (x: 20., y: 20.),
+                    (x: 20., y: 10.),
+                    (x: 10., y: 10.),
+                    (x: 10., y: 20.),
+                ],
+                [
+                    (x: 20., y: 20.),
+                    (x: 10., y: 20.),
+                    (x: 10., y: 10.),
+                    (x: 20., y: 10.),
+                ],
+            ],
+        );
+        let opts = NormalizeOptions::builder().dedupe_interiors(true).build();
+        let norm = poly.normalized_with(opts);
+        assert_eq!(norm.interiors(), &poly.interiors()[..2]);
+        assert_eq!(poly.normalized().interiors().len(), 4);
+
+        let (_, report) = normalize_report_with(&poly, opts);
+        assert_eq!(report.deduped_interiors, 2);
+        assert_eq!(report.reversed_interiors, vec![2]);
+    }
+
+    #[test]
+    fn can_remove_spikes() {
+        let opts = NormalizeOptions {
+            remove_spikes: true,
+            ..Default::default()
+        };
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+        ];
+        let spike = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: 9.),
+            (x: 2., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+        ];
+        assert_eq!(spike.normalized(), spike);
+        let norm = spike.normalized_with(opts);
+        assert_eq!(
+            norm,
+            polygon![
+                (x: 0., y: 0.),
+                (x: 0., y: 4.),
+                (x: 2., y: 4.),
+                (x: 4., y: 4.),
+                (x: 4., y: 0.),
+            ]
+        );
+        let both = NormalizeOptions {
+            remove_collinear: true,
+            ..opts
+        };
+        assert_eq!(spike.normalized_with(both), square);
+
+        // An antenna of two segments, and a spike across the start of the ring
+        let chain = polygon![
+            (x: 0., y: 4.),
+            (x: 4., y: 4.),
+            (x: 6., y: 6.),
+            (x: 8., y: 8.),
+            (x: 6., y: 6.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+            (x: 0., y: 0.),
+            (x: -3., y: -3.),
+            (x: 0., y: 0.),
+        ];
+        assert_eq!(chain.normalized_with(both).exterior().0.len(), 5);
+        assert!(chain.normalized_with(both).is_normalized());
+
+        // A narrow but genuine sliver is not a back-track and is kept
+        let sliver = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: 9.),
+            (x: 2.01, y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+        ];
+        assert_eq!(sliver.normalized_with(opts), sliver);
+
+        // Repeated vertices that are not left behind by a spike are for `dedupe_consecutive`
+        let repeated = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+            (x: 0., y: 4.),
+            (x: 2., y: 4.),
+            (x: 2., y: 9.),
+            (x: 2., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let norm = repeated.normalized_with(opts);
+        assert_eq!(
+            norm,
+            polygon![
+                (x: 0., y: 0.),
+                (x: 0., y: 4.),
+                (x: 0., y: 4.),
+                (x: 2., y: 4.),
+                (x: 4., y: 4.),
+                (x: 4., y: 0.),
+                (x: 0., y: 0.),
+            ]
+        );
+        let (_, report) = normalize_report_with(&repeated, opts);
+        assert_eq!(report.spike_points, 2);
+    }
+
+    #[test]
+    fn can_sort_multi_polygon_members() {
+        let opts = NormalizeOptions {
+            sort_polygons: true,
+            ..Default::default()
+        };
+        let (square, _) = get_bad_outer_poly();
+        let (holed, bad_holed) = get_bad_outer_bad_inner_poly();
+        let a = MultiPolygon(vec![square.clone(), bad_holed.clone()]);
+        let b = MultiPolygon(vec![bad_holed, square.clone()]);
+        assert_ne!(a.normalized(), b.normalized());
+        assert_eq!(a.normalized_with(opts), MultiPolygon(vec![holed, square]));
+        assert_eq!(a.normalized_with(opts), b.normalized_with(opts));
+        assert_eq!(a.try_normalized_with(opts), Ok(b.normalized_with(opts)));
+    }
+
+    #[test]
+    fn sorting_members_with_nan_coordinates_does_not_panic() {
+        use geo::MapCoords;
+
+        let (square, _) = get_bad_outer_poly();
+        // Comparing a NaN x as equal to every other x makes the order intransitive for members
+        // whose x and y grow in opposite directions. The sort only notices with enough members.
+        let nan = square.map_coords(|c| geo::coord! { x: f64::NAN, y: c.y - 10. });
+        let members = (0..40)
+            .map(|i| {
+                if i % 3 == 0 {
+                    nan.clone()
+                } else {
+                    let i = f64::from(i);
+                    square.map_coords(|c| geo::coord! { x: c.x + i, y: c.y - i })
+                }
+            })
+            .collect::<Vec<_>>();
+        let opts = NormalizeOptions::builder().sort_polygons(true).build();
+        let sorted = MultiPolygon(members.clone()).normalized_with(opts);
+        let first_nan = sorted.0.iter().position(|p| p.exterior().0[0].x.is_nan());
+        assert_eq!(first_nan, Some(26));
+        assert!(sorted.0[26..].iter().all(|p| p.exterior().0[0].x.is_nan()));
+        assert!(sorted.0[..26]
+            .windows(2)
+            .all(|w| w[0].exterior().0[0].x < w[1].exterior().0[0].x));
+        #[cfg(feature = "rayon")]
+        assert_eq!(
+            MultiPolygon(members).normalized_par_with(opts).0[..26],
+            sorted.0[..26]
+        );
+    }
+
+    #[test]
+    fn can_normalize_integer_polygon() {
+        let bad: Polygon<i64> = polygon!(
+            exterior: [
+                (x: 0, y: 0),
+                (x: 50, y: 0),
+                (x: 50, y: 50),
+                (x: 0, y: 50),
+            ],
+            interiors: [
+                [
+                    (x: 10, y: 10),
+                    (x: 10, y: 20),
+                    (x: 20, y: 20),
+                    (x: 20, y: 10),
+                ],
+            ],
+        );
+        let good: Polygon<i64> = polygon!(
+            exterior: [
+                (x: 0, y: 0),
+                (x: 0, y: 50),
+                (x: 50, y: 50),
+                (x: 50, y: 0),
+            ],
+            interiors: [
+                [
+                    (x: 10, y: 10),
+                    (x: 20, y: 10),
+                    (x: 20, y: 20),
+                    (x: 10, y: 20),
+                ],
+            ],
+        );
+        assert!(!bad.is_normalized());
+        assert_eq!(bad.normalized(), good);
+        assert_eq!(bad.try_normalized(), Ok(good));
+    }
+
+    /// Exercise the API with nothing but the `CoordNum` bound
+    fn normalize_generic<T: CoordNum + Eq + core::hash::Hash>(bad: &Polygon<T>, good: &Polygon<T>) {
+        let opts = NormalizeOptions::builder()
+            .canonical_start(true)
+            .sort_interiors(true)
+            .build();
+        assert_eq!(bad.normalized(), *good);
+        assert_eq!(bad.clone().into_normalized(), *good);
+        assert_eq!(
+            bad.try_normalized_with(opts),
+            Ok(good.normalized_with(opts))
+        );
+        assert!(good.matches(Orientation::Ogc));
+        assert!(validate_ogc(good).is_empty());
+        assert!(!normalize_report(bad).1.is_unchanged());
+        assert_eq!(NormalizedKey::new(bad), NormalizedKey::new(good));
+        assert_eq!(
+            NormalizedKey::from_rounded(bad, 2),
+            NormalizedKey::from_rounded(good, 2)
+        );
+        assert_eq!(
+            normalize_ring(bad.exterior(), WindingOrder::Clockwise),
+            *good.exterior()
+        );
+        assert_eq!(
+            MultiPolygon(vec![bad.clone()]).normalized(),
+            MultiPolygon(vec![good.clone()])
+        );
+    }
+
+    #[test]
+    fn api_only_needs_geo_num() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let cast = |poly: &Polygon<f64>| -> (Polygon<i32>, Polygon<i16>) {
+            let ring = |ring: &LineString<f64>| {
+                (
+                    ring.coords().map(|c| (c.x as i32, c.y as i32)).collect(),
+                    ring.coords().map(|c| (c.x as i16, c.y as i16)).collect(),
+                )
+            };
+            let (exterior32, exterior16) = ring(poly.exterior());
+            let (interiors32, interiors16) = poly.interiors().iter().map(ring).unzip();
+            (
+                Polygon::new(exterior32, interiors32),
+                Polygon::new(exterior16, interiors16),
+            )
+        };
+        let ((good32, good16), (bad32, bad16)) = (cast(&good), cast(&bad));
+        normalize_generic(&bad32, &good32);
+        normalize_generic(&bad16, &good16);
+    }
+
+    /// A decimal with six fixed decimal places, standing in for exact decimal types such as
+    /// `rust_decimal::Decimal` that implement the same traits. It implements `CoordNum` but not
+    /// geo's `GeoNum`, which normalization does not need.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Fixed(i64);
+
+    const FIXED_ONE: i64 = 1_000_000;
+
+    impl core::ops::Add for Fixed {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Fixed(self.0 + rhs.0)
+        }
+    }
+
+    impl core::ops::Sub for Fixed {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Fixed(self.0 - rhs.0)
+        }
+    }
+
+    impl core::ops::Mul for Fixed {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Fixed((i128::from(self.0) * i128::from(rhs.0) / i128::from(FIXED_ONE)) as i64)
+        }
+    }
+
+    impl core::ops::Div for Fixed {
+        type Output = Self;
+        fn div(self, rhs: Self) -> Self {
+            Fixed((i128::from(self.0) * i128::from(FIXED_ONE) / i128::from(rhs.0)) as i64)
+        }
+    }
+
+    impl core::ops::Rem for Fixed {
+        type Output = Self;
+        fn rem(self, rhs: Self) -> Self {
+            Fixed(self.0 % rhs.0)
+        }
+    }
+
+    impl num_traits::Zero for Fixed {
+        fn zero() -> Self {
+            Fixed(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl num_traits::One for Fixed {
+        fn one() -> Self {
+            Fixed(FIXED_ONE)
+        }
+    }
+
+    impl num_traits::Num for Fixed {
+        type FromStrRadixErr = ();
+        fn from_str_radix(_: &str, _: u32) -> Result<Self, ()> {
+            Err(())
+        }
+    }
+
+    impl num_traits::ToPrimitive for Fixed {
+        fn to_i64(&self) -> Option<i64> {
+            Some(self.0 / FIXED_ONE)
+        }
+        fn to_u64(&self) -> Option<u64> {
+            (self.0 / FIXED_ONE).to_u64()
+        }
+        fn to_f64(&self) -> Option<f64> {
+            Some(self.0 as f64 / FIXED_ONE as f64)
+        }
+    }
+
+    impl num_traits::NumCast for Fixed {
+        fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+            n.to_f64()
+                .map(|v| Fixed((v * FIXED_ONE as f64).round() as i64))
+        }
+    }
+
+    #[test]
+    fn can_normalize_exact_decimal_coordinates() {
+        let fixed = |poly: &Polygon<f64>| -> Polygon<Fixed> {
+            geo::MapCoords::map_coords(poly, |c| Coord {
+                x: num_traits::NumCast::from(c.x + 0.125).unwrap(),
+                y: num_traits::NumCast::from(c.y + 0.125).unwrap(),
+            })
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (good, bad) = (fixed(&good), fixed(&bad));
+        assert!(!bad.is_normalized());
+        assert_eq!(bad.normalized(), good);
+        assert_eq!(bad.try_normalized(), Ok(good.clone()));
+        assert!(validate_ogc(&good).is_empty());
+        assert_eq!(NormalizedKey::new(&bad), NormalizedKey::new(&good));
+        let opts = NormalizeOptions::builder().round_decimals(Some(3)).build();
+        assert_eq!(bad.normalized_with(opts), good);
+    }
+
+    #[test]
+    fn can_report_ring_windings() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(exterior_winding(&good), Some(WindingOrder::Clockwise));
+        assert_eq!(exterior_winding(&bad), Some(WindingOrder::CounterClockwise));
+        assert_eq!(
+            interior_windings(&good),
+            vec![Some(WindingOrder::CounterClockwise)]
+        );
+        assert_eq!(interior_windings(&bad), vec![Some(WindingOrder::Clockwise)]);
+
+        let mut degenerate = good.clone();
+        degenerate.interiors_push(vec![(1.0, 1.0), (2.0, 2.0)]);
+        assert_eq!(
+            interior_windings(&degenerate),
+            vec![Some(WindingOrder::CounterClockwise), None]
+        );
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(exterior_winding(&empty), None);
+        assert!(interior_windings(&empty).is_empty());
+    }
+
+    #[test]
+    fn does_not_change_empty_polygon() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.normalized(), empty);
+
+        let (_, bad) = get_good_outer_bad_inner_poly();
+        let hollow = Polygon::new(LineString::new(vec![]), bad.interiors().to_vec());
+        assert_eq!(hollow.normalized(), hollow);
+        let opts = NormalizeOptions {
+            dedupe_consecutive: true,
+            remove_collinear: true,
+            canonical_start: true,
+            ..Default::default()
+        };
+        assert_eq!(hollow.normalized_with(opts), hollow);
+    }
+
+    #[test]
+    fn normalized_coords_follow_the_normalized_rings() {
+        let flatten = |poly: &Polygon<f64>| -> Vec<(RingRole, Coord<f64>)> {
+            let exterior = poly.exterior().coords().map(|c| (RingRole::Exterior, *c));
+            let interiors = poly.interiors().iter().flat_map(|ring| ring.coords());
+            exterior
+                .chain(interiors.map(|c| (RingRole::Interior, *c)))
+                .collect()
+        };
+        let (_, bad) = get_bad_outer_bad_inner_poly();
+        let (_, good_outer) = get_good_outer_bad_inner_poly();
+        let (_, bad_outer) = get_bad_outer_good_inner_poly();
+        let hollow = Polygon::new(LineString::new(vec![]), bad.interiors().to_vec());
+        for poly in [bad, good_outer, bad_outer, hollow] {
+            assert_eq!(
+                normalized_coords(&poly).collect::<Vec<_>>(),
+                flatten(&poly.normalized())
+            );
+            for orientation in [Orientation::GeoJson, Orientation::OppositeInterior] {
+                let opts = NormalizeOptions {
+                    orientation,
+                    ..Default::default()
+                };
+                assert_eq!(
+                    normalized_coords_with(&poly, orientation).collect::<Vec<_>>(),
+                    flatten(&poly.normalized_with(opts))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_polygon_can_only_hold_normalized_polygons() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let norm = NormalizedPolygon::from(bad);
+        assert!(norm.is_normalized());
+        assert_eq!(*norm, good);
+        assert_eq!(norm.as_ref().interiors().len(), 1);
+        assert_eq!(NormalizedPolygon::from(good.clone()), norm);
+        assert_eq!(Polygon::from(norm.clone()), good);
+        assert_eq!(norm.into_inner(), good);
+    }
+
+    #[test]
+    fn does_not_change_empty_collections() {
+        let opts = NormalizeOptions::builder()
+            .sort_polygons(true)
+            .drop_empty(true)
+            .build();
+        let collection = GeometryCollection::<f64>(vec![]);
+        assert_eq!(collection.normalized(), collection);
+        assert_eq!(collection.normalized_with(opts), collection);
+        assert_eq!(collection.clone().into_normalized(), collection);
+        assert_eq!(collection.try_normalized(), Ok(collection.clone()));
+        assert!(collection.is_normalized());
+        let multi = MultiPolygon::<f64>(vec![]);
+        assert_eq!(multi.normalized(), multi);
+        assert_eq!(multi.normalized_with(opts), multi);
+        assert_eq!(multi.clone().into_normalized(), multi);
+        assert_eq!(multi.try_normalized(), Ok(multi.clone()));
+        assert!(multi.is_normalized());
+        #[cfg(feature = "rayon")]
+        {
+            assert_eq!(collection.normalized_par(), collection);
+            assert_eq!(multi.normalized_par(), multi);
+        }
+    }
+
+    #[test]
+    fn can_normalize_polygon_without_interiors() {
+        let (good, bad) = get_bad_outer_poly();
+        assert!(bad.interiors().is_empty());
+        let norm = bad.normalized();
+        assert_eq!(norm, good);
+        assert!(norm.interiors().is_empty());
+    }
+
+    #[test]
+    fn does_not_change_non_areal_geometries() {
+        let (_, bad) = get_bad_outer_poly();
+        let line_string = bad.exterior().clone();
+        assert_eq!(line_string.normalized(), line_string);
+        assert!(line_string.is_normalized());
+        let multi_line_string = MultiLineString(vec![line_string.clone()]);
+        assert_eq!(multi_line_string.normalized(), multi_line_string);
+        let point = geo::point!(x: 1.0, y: 2.0);
+        assert_eq!(point.normalized(), point);
+        assert_eq!(point.try_normalized(), Ok(point));
+        let multi_point = MultiPoint(vec![point, point]);
+        assert_eq!(multi_point.normalized(), multi_point);
+        let line = Line::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(line.normalized(), line);
+    }
+
+    #[test]
+    fn into_normalized_reuses_allocations() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let exterior = bad.exterior().0.as_ptr();
+        let interior = bad.interiors()[0].0.as_ptr();
+        let norm = bad.into_normalized();
+        assert_eq!(norm, good);
+        assert_eq!(norm.exterior().0.as_ptr(), exterior);
+        assert_eq!(norm.interiors()[0].0.as_ptr(), interior);
+
+        let (good, bad) = get_bad_outer_good_inner_poly();
+        let mp = MultiPolygon(vec![bad.clone(), good.clone()]);
+        let members = mp.0.as_ptr();
+        let norm = mp.into_normalized();
+        assert_eq!(norm.0.as_ptr(), members);
+        assert_eq!(norm, MultiPolygon(vec![good.clone(), good.clone()]));
+
+        let gc = GeometryCollection(vec![Geometry::Polygon(bad)]);
+        assert_eq!(
+            gc.into_normalized(),
+            GeometryCollection(vec![Geometry::Polygon(good)])
+        );
+    }
+
+    #[test]
+    fn can_normalize_f32_polygon() {
+        use geo::MapCoords;
+        let to_f32 = |c: geo::Coord<f64>| geo::Coord {
+            x: c.x as f32,
+            y: c.y as f32,
+        };
+        let (good, bad) = get_bad_outer_poly();
+        let (good, bad) = (good.map_coords(to_f32), bad.map_coords(to_f32));
+        assert_eq!(bad.normalized(), good);
+        assert_eq!(good.normalized(), good);
+    }
+
+    #[test]
+    fn can_normalize_f32_bad_outer_bad_inner_polygon() {
+        use geo::MapCoords;
+        let to_f32 = |c: geo::Coord<f64>| geo::Coord {
+            x: c.x as f32,
+            y: c.y as f32,
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (good, bad) = (good.map_coords(to_f32), bad.map_coords(to_f32));
+        assert!(!bad.is_normalized());
+        assert_eq!(bad.normalized(), good);
+        assert_eq!(bad.try_normalized(), Ok(good.clone()));
+        assert_eq!(
+            MultiPolygon(vec![bad]).normalized(),
+            MultiPolygon(vec![good])
+        );
+    }
+
+    #[test]
+    fn can_normalize_geometry_stream() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let point = Geometry::Point(geo::point!(x: 1.0, y: 1.0));
+        let stream = vec![
+            Geometry::Polygon(bad.clone()),
+            point.clone(),
+            Geometry::MultiPolygon(MultiPolygon(vec![bad])),
+        ]
+        .into_iter();
+        let norm = normalize_all(stream).collect::<Vec<_>>();
+        assert_eq!(
+            norm,
+            vec![
+                Geometry::Polygon(good.clone()),
+                point,
+                Geometry::MultiPolygon(MultiPolygon(vec![good])),
+            ]
+        );
+    }
+
+    #[test]
+    fn try_normalized_locates_non_finite_coordinates() {
+        let (good, _) = get_good_outer_bad_inner_poly();
+        let mut corrupt = good.clone();
+        corrupt.interiors_mut(|rings| rings[0].0[2].x = f64::INFINITY);
+        assert_eq!(
+            corrupt.try_normalized(),
+            Err(NormalizeError::NonFinite {
+                ring_index: 1,
+                point_index: 2
+            })
+        );
+        assert_eq!(
+            NormalizeError::NonFinite {
+                ring_index: 1,
+                point_index: 2
+            }
+            .to_string(),
+            "coordinate 2 of ring 1 is not finite"
+        );
+    }
+
+    #[test]
+    fn can_normalize_triangle() {
+        let ccw = Triangle::new((0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into());
+        assert!(!ccw.is_normalized());
+        let cw = ccw.normalized();
+        assert!(cw.is_normalized());
+        assert_eq!(
+            cw.to_array(),
+            [(0.0, 0.0).into(), (0.0, 1.0).into(), (1.0, 0.0).into()]
+        );
+        assert_eq!(cw.normalized(), cw);
+
+        let opts = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        };
+        assert_eq!(cw.normalized_with(opts), ccw);
+
+        let flat = Triangle::new((0.0, 0.0).into(), (1.0, 1.0).into(), (2.0, 2.0).into());
+        assert_eq!(flat.normalized(), flat);
+    }
+
+    #[test]
+    fn does_not_change_rect() {
+        let rect = Rect::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(rect.normalized(), rect);
+        assert!(rect.is_normalized());
+    }
+
+    #[test]
+    fn can_normalize_iterator_chains() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let polys = vec![bad.clone(), good.clone()];
+        let norm = polys.into_iter().normalized().collect::<Vec<_>>();
+        assert_eq!(norm, vec![good.clone(), good.clone()]);
+
+        let geoms = vec![Geometry::Polygon(bad), Geometry::Point(Point::new(1., 2.))];
+        let norm = geoms.into_iter().normalized();
+        assert_eq!(norm.size_hint(), (2, Some(2)));
+        assert_eq!(
+            norm.collect::<Vec<_>>(),
+            vec![Geometry::Polygon(good), Geometry::Point(Point::new(1., 2.))]
+        );
+    }
+
+    #[test]
+    fn can_normalize_vecs_and_slices_of_geometries() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let point = Geometry::Point(Point::new(1., 2.));
+        let mut geoms = vec![
+            Geometry::Polygon(bad.clone()),
+            point.clone(),
+            Geometry::MultiPolygon(MultiPolygon(vec![bad.clone()])),
+        ];
+        let expected = vec![
+            Geometry::Polygon(good.clone()),
+            point,
+            Geometry::MultiPolygon(MultiPolygon(vec![good.clone()])),
+        ];
+        assert!(!geoms.is_normalized());
+        assert_eq!(geoms.normalized(), expected);
+        assert_eq!(geoms.try_normalized(), Ok(expected.clone()));
+
+        normalize_geometries(&mut geoms[..2]);
+        assert_eq!(geoms[..2], expected[..2]);
+        assert_eq!(geoms[2], Geometry::MultiPolygon(MultiPolygon(vec![bad])));
+    }
+
+    #[test]
+    fn can_normalize_slice_in_place() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let mut polys = vec![bad.clone(), good.clone(), bad];
+        let untouched = polys[1].exterior().0.as_ptr();
+        normalize_slice(&mut polys);
+        assert_eq!(polys, vec![good.clone(), good.clone(), good]);
+        assert_eq!(polys[1].exterior().0.as_ptr(), untouched);
+
+        let mut empty: Vec<Polygon<f64>> = vec![];
+        normalize_slice(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn normalized_by_can_keep_interior_rings() {
+        let (_, bad) = get_bad_outer_bad_inner_poly();
+        let (good, _) = get_bad_outer_good_inner_poly();
+        let norm = bad.normalized_by(|role, _| match role {
+            RingRole::Exterior => Some(WindingOrder::Clockwise),
+            RingRole::Interior => None,
+        });
+        assert_eq!(norm.exterior(), good.exterior());
+        assert_eq!(norm.interiors(), bad.interiors());
+
+        let all = bad.normalized_by(|role, _| match role {
+            RingRole::Exterior => Orientation::Ogc.exterior_winding(),
+            RingRole::Interior => Orientation::Ogc.interior_winding(),
+        });
+        assert_eq!(all, bad.normalized());
+        assert_eq!(bad.normalized_by(|_, _| None), bad);
+    }
+
+    #[test]
+    fn normalized_by_passes_interior_index() {
+        let (_, bad) = get_good_outer_bad_inner_poly();
+        let mut mp = MultiPolygon(vec![bad.clone(), bad.clone()]);
+        let ring = bad.interiors()[0].clone();
+        mp.0[1].interiors_push(ring);
+        let norm = mp.normalized_by(|role, i| match (role, i) {
+            (RingRole::Interior, 1) => Some(WindingOrder::CounterClockwise),
+            _ => None,
+        });
+        assert_eq!(norm.0[0], bad);
+        assert_eq!(norm.0[1].interiors()[0], bad.interiors()[0]);
+        assert_eq!(
+            ring::winding_order(&norm.0[1].interiors()[1]),
+            Some(WindingOrder::CounterClockwise)
+        );
+    }
+
+    #[test]
+    fn normalize_mut_reuses_ring_buffers() {
+        let n = 10_000;
+        let coords = (0..=n)
+            .map(|i| {
+                let angle = core::f64::consts::TAU * f64::from(i % n) / f64::from(n);
+                (angle.cos(), angle.sin())
+            })
+            .collect::<Vec<_>>();
+        let mut poly = Polygon::new(LineString::from(coords), vec![]);
+        let ptr = poly.exterior().0.as_ptr();
+        let capacity = poly.exterior().0.capacity();
+        poly.normalize_mut();
+        assert!(poly.is_normalized());
+        assert_eq!(poly.exterior().0.as_ptr(), ptr);
+        assert_eq!(poly.exterior().0.capacity(), capacity);
+    }
+
+    #[test]
+    fn normalized_cow_borrows_valid_geometries() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert!(matches!(good.normalized_cow(), Cow::Borrowed(p) if *p == good));
+        assert!(matches!(bad.normalized_cow(), Cow::Owned(p) if p == good));
+
+        let mp = MultiPolygon(vec![good.clone(), good.clone()]);
+        assert!(matches!(mp.normalized_cow(), Cow::Borrowed(_)));
+        let mp = MultiPolygon(vec![good.clone(), bad.clone()]);
+        assert_eq!(
+            mp.normalized_cow().into_owned(),
+            MultiPolygon(vec![good.clone(), good.clone()])
+        );
+
+        let gc = GeometryCollection(vec![Geometry::Polygon(good.clone())]);
+        assert!(matches!(gc.normalized_cow(), Cow::Borrowed(_)));
+        let gc = GeometryCollection(vec![Geometry::Polygon(bad)]);
+        assert!(matches!(gc.normalized_cow(), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn esri_orientation_matches_shapefile_winding() {
+        let opts = NormalizeOptions {
+            orientation: Orientation::Esri,
+            ..Default::default()
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let norm = bad.normalized_with(opts);
+        assert_eq!(norm, good);
+        assert_eq!(exterior_winding(&norm), Some(WindingOrder::Clockwise));
+        assert_eq!(
+            interior_windings(&norm),
+            vec![Some(WindingOrder::CounterClockwise)]
+        );
+    }
+
+    #[test]
+    fn d3_orientation_matches_geojson_winding() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let d3 = NormalizeOptions {
+            orientation: Orientation::D3,
+            ..Default::default()
+        };
+        let geojson = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        };
+        let norm = good.normalized_with(d3);
+        assert_eq!(norm, bad.normalized_with(d3));
+        assert_eq!(norm, good.normalized_with(geojson));
+        assert_eq!(
+            exterior_winding(&norm),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert_eq!(
+            interior_windings(&norm),
+            vec![Some(WindingOrder::Clockwise)]
+        );
+    }
+
+    #[test]
+    fn can_normalize_through_boxes_and_references() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let boxed = Box::new(bad.clone());
+        assert!(!boxed.is_normalized());
+        assert_eq!(boxed.normalized(), Box::new(good.clone()));
+        assert_eq!(boxed.try_normalized(), Ok(Box::new(good.clone())));
+
+        let reference: &Polygon<f64> = &bad;
+        let norm: Polygon<f64> = reference.normalized();
+        assert_eq!(norm, good);
+
+        fn normalize_generic<T: CoordNum, G: Normalized<T>>(mut geom: G) -> G {
+            geom.normalize_mut();
+            geom
+        }
+        assert_eq!(*normalize_generic(boxed), good);
+    }
+
+    #[test]
+    fn reports_reversed_rings_and_removed_points() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (norm, report) = normalize_report(&bad);
+        assert_eq!(norm, good);
+        assert!(report.exterior_reversed);
+        assert_eq!(report.reversed_interiors, vec![0]);
+        assert_eq!(report.deduped_points, 0);
+
+        let (norm, report) = normalize_report(&good);
+        assert_eq!(norm, good);
+        assert!(report.is_unchanged());
+
+        let opts = NormalizeOptions {
+            dedupe_consecutive: true,
+            remove_collinear: true,
+            drop_degenerate_interiors: true,
+            ..Default::default()
+        };
+        let (_, bad) = get_good_outer_bad_inner_poly();
+        let mut noisy = Polygon::new(
+            LineString::from(vec![
+                (0., 0.),
+                (0., 0.),
+                (0., 25.),
+                (0., 50.),
+                (50., 50.),
+                (50., 0.),
+            ]),
+            bad.interiors().to_vec(),
+        );
+        noisy.interiors_push(LineString::from(vec![(30., 30.), (40., 40.), (30., 30.)]));
+        let (norm, report) = normalize_report_with(&noisy, opts);
+        assert_eq!(norm, noisy.normalized_with(opts));
+        assert!(!report.exterior_reversed);
+        assert_eq!(report.reversed_interiors, vec![0]);
+        assert_eq!(report.deduped_points, 1);
+        assert_eq!(report.collinear_points, 1);
+        assert_eq!(report.dropped_interiors, 1);
+    }
+
+    #[test]
+    fn normalizes_nested_geometry_collections() {
+        let (good, bad) = get_bad_outer_poly();
+        let nested = |poly: &Polygon<f64>| {
+            GeometryCollection(vec![
+                Geometry::Point(Point::new(1., 2.)),
+                Geometry::GeometryCollection(GeometryCollection(vec![
+                    Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(
+                        poly.clone(),
+                    )])),
+                ])),
+            ])
+        };
+        let input = nested(&bad);
+        assert!(!input.is_normalized());
+        assert_eq!(input.normalized(), nested(&good));
+        assert_eq!(input.try_normalized(), Ok(nested(&good)));
+        assert!(nested(&good).is_normalized());
+        assert_eq!(
+            Geometry::GeometryCollection(input).normalized(),
+            Geometry::GeometryCollection(nested(&good))
+        );
+    }
+
+    #[test]
+    fn valid_multi_polygons_are_not_rebuilt() {
+        let (good, _) = get_bad_outer_bad_inner_poly();
+        let mp = MultiPolygon(vec![good; 1000]);
+        assert!(matches!(mp.normalized_cow(), Cow::Borrowed(_)));
+
+        let members = mp.0.as_ptr();
+        let ring = mp.0[0].exterior().0.as_ptr();
+        let norm = mp.into_normalized();
+        assert_eq!(norm.0.as_ptr(), members);
+        assert_eq!(norm.0[0].exterior().0.as_ptr(), ring);
+    }
+
+    #[test]
+    fn can_normalize_tuples_and_arrays() {
+        let (good, bad) = get_bad_outer_poly();
+        let (good_inner, bad_inner) = get_good_outer_bad_inner_poly();
+
+        let pair = (bad.clone(), good_inner.clone());
+        assert!(!pair.is_normalized());
+        assert_eq!(pair.normalized(), (good.clone(), good_inner.clone()));
+
+        let triple = (good.clone(), bad_inner.clone(), bad.clone());
+        assert_eq!(
+            triple.try_normalized(),
+            Ok((good.clone(), good_inner.clone(), good.clone()))
+        );
+
+        let array = [bad.clone(), good.clone(), bad_inner];
+        assert_eq!(array.normalized(), [good.clone(), good.clone(), good_inner]);
+        assert!([good.clone(), good].is_normalized());
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(
+            [bad, empty].try_normalized(),
+            Err(NormalizeError::EmptyExterior)
+        );
+    }
+
+    #[test]
+    fn validate_ogc_reports_ring_violations() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert!(validate_ogc(&good).is_empty());
+        assert_eq!(
+            validate_ogc(&bad),
+            vec![
+                OgcViolation::WrongWinding {
+                    role: RingRole::Exterior,
+                    index: 0,
+                    expected: WindingOrder::Clockwise,
+                },
+                OgcViolation::WrongWinding {
+                    role: RingRole::Interior,
+                    index: 0,
+                    expected: WindingOrder::CounterClockwise,
+                },
+            ]
+        );
+
+        let mut broken = good.clone();
+        broken.interiors_push(LineString::from(vec![(30., 30.), (31., 31.)]));
+        broken.interiors_push(LineString::from(vec![(30., 30.), (31., 31.), (32., 32.)]));
+        assert_eq!(
+            validate_ogc(&broken),
+            vec![
+                OgcViolation::TooFewPoints {
+                    role: RingRole::Interior,
+                    index: 1,
+                    count: 3,
+                },
+                OgcViolation::UndefinedWinding {
+                    role: RingRole::Interior,
+                    index: 2,
+                },
+            ]
+        );
+        assert_eq!(validate_ogc(&good), validate_ogc(&good.normalized()));
+
+        // geo finds a winding for a figure of eight, but with equal lobes its area is zero
+        let eight = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+        let mirrored = LineString::from(eight.0.iter().rev().copied().collect::<Vec<_>>());
+        for ring in [eight, mirrored] {
+            assert!(ring.winding_order().is_some());
+            assert_eq!(
+                validate_ogc(&Polygon::new(ring.clone(), vec![])),
+                vec![OgcViolation::UndefinedWinding {
+                    role: RingRole::Exterior,
+                    index: 0,
+                }]
+            );
+            assert_eq!(
+                validate_ogc(&Polygon::new(good.exterior().clone(), vec![ring])),
+                vec![OgcViolation::UndefinedWinding {
+                    role: RingRole::Interior,
+                    index: 0,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn validate_ogc_multi_screens_member_bounding_boxes() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let far = Polygon::new(
+            LineString::from(vec![(60., 0.), (60., 10.), (70., 10.), (70., 0.)]),
+            vec![],
+        );
+        let touching = Polygon::new(
+            LineString::from(vec![(70., 10.), (70., 20.), (80., 20.), (80., 10.)]),
+            vec![],
+        );
+        let empty = Polygon::new(LineString::new(vec![]), vec![]);
+        assert!(validate_ogc_multi(&MultiPolygon(vec![good.clone(), far.clone()])).is_empty());
+        assert_eq!(
+            validate_ogc_multi(&MultiPolygon(vec![good.clone(), far, empty, touching, bad])),
+            vec![
+                (
+                    2,
+                    OgcViolation::TooFewPoints {
+                        role: RingRole::Exterior,
+                        index: 0,
+                        count: 0,
+                    }
+                ),
+                (
+                    4,
+                    OgcViolation::WrongWinding {
+                        role: RingRole::Exterior,
+                        index: 0,
+                        expected: WindingOrder::Clockwise,
+                    }
+                ),
+                (
+                    4,
+                    OgcViolation::WrongWinding {
+                        role: RingRole::Interior,
+                        index: 0,
+                        expected: WindingOrder::CounterClockwise,
+                    }
+                ),
+                (0, OgcViolation::PossibleOverlap { a: 0, b: 4 }),
+                (1, OgcViolation::PossibleOverlap { a: 1, b: 3 }),
+            ]
+        );
+        // A polygon inside the hole of another is valid, but still flagged
+        let island = Polygon::new(
+            LineString::from(vec![(12., 12.), (12., 18.), (18., 18.), (18., 12.)]),
+            vec![],
+        );
+        assert_eq!(
+            validate_ogc_multi(&MultiPolygon(vec![good, island])),
+            vec![(0, OgcViolation::PossibleOverlap { a: 0, b: 1 })]
+        );
+    }
+
+    #[test]
+    fn validate_ogc_reports_interiors_larger_than_the_exterior() {
+        let (good, _) = get_bad_outer_bad_inner_poly();
+        let (exterior, interiors) = good.clone().into_inner();
+        let swapped = Polygon::new(interiors[0].clone(), vec![exterior]);
+        let norm = swapped.normalized();
+        assert_eq!(exterior_winding(&norm), Some(WindingOrder::Clockwise));
+        assert_eq!(
+            interior_windings(&norm),
+            vec![Some(WindingOrder::CounterClockwise)]
+        );
+        assert_eq!(
+            validate_ogc(&norm),
+            vec![OgcViolation::InteriorLargerThanExterior { index: 0 }]
+        );
+        assert_eq!(
+            validate_ogc_multi(&MultiPolygon(vec![good, norm])),
+            vec![
+                (1, OgcViolation::InteriorLargerThanExterior { index: 0 }),
+                (0, OgcViolation::PossibleOverlap { a: 0, b: 1 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_and_validate_reports_what_normalizing_left() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(normalize_and_validate(&bad), (good.clone(), vec![]));
+
+        let mut broken = bad.clone();
+        broken.interiors_push(LineString::from(vec![(30., 30.), (31., 31.)]));
+        broken.interiors_push(LineString::from(vec![(30., 30.), (31., 31.), (32., 32.)]));
+        let (norm, violations) = normalize_and_validate(&broken);
+        assert_eq!(norm, broken.normalized());
+        assert_eq!(
+            violations,
+            vec![
+                OgcViolation::TooFewPoints {
+                    role: RingRole::Interior,
+                    index: 1,
+                    count: 3,
+                },
+                OgcViolation::UndefinedWinding {
+                    role: RingRole::Interior,
+                    index: 2,
+                },
+            ]
+        );
+
+        // Normalization leaves a figure of eight as it is, so its winding is not reported as
+        // wrong afterwards
+        let eight = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+        let mirrored = LineString::from(eight.0.iter().rev().copied().collect::<Vec<_>>());
+        for ring in [eight, mirrored] {
+            let poly = Polygon::new(good.exterior().clone(), vec![ring]);
+            let (norm, violations) = normalize_and_validate(&poly);
+            assert!(norm.is_normalized());
+            assert!(!violations
+                .iter()
+                .any(|v| matches!(v, OgcViolation::WrongWinding { .. })));
+            assert_eq!(
+                violations,
+                vec![OgcViolation::UndefinedWinding {
+                    role: RingRole::Interior,
+                    index: 0,
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn denormalized_is_the_mirror_of_normalized() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let denorm = good.denormalized();
+        assert_eq!(denorm, bad);
+        assert_eq!(denorm.denormalized(), denorm);
+        assert_eq!(bad.denormalized(), bad);
+        assert_eq!(
+            exterior_winding(&denorm),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert_eq!(
+            interior_windings(&denorm),
+            vec![Some(WindingOrder::Clockwise)]
+        );
+    }
+
+    #[test]
+    fn normalization_is_idempotent() {
+        let fixtures = [
+            get_bad_outer_poly(),
+            get_good_outer_bad_inner_poly(),
+            get_bad_outer_bad_inner_poly(),
+            get_bad_outer_good_inner_poly(),
+        ];
+        let polys = fixtures
+            .iter()
+            .flat_map(|(good, bad)| [good.clone(), bad.clone()])
+            .collect::<Vec<_>>();
+        let all_options = NormalizeOptions::builder()
+            .canonical_start(true)
+            .dedupe_consecutive(true)
+            .remove_collinear(true)
+            .remove_spikes(true)
+            .sort_interiors(true)
+            .sort_polygons(true)
+            .drop_degenerate_interiors(true)
+            .round_decimals(Some(6))
+            .build();
+        let option_sets = [
+            NormalizeOptions::default(),
+            NormalizeOptions::builder()
+                .orientation(Orientation::GeoJson)
+                .build(),
+            all_options,
+        ];
+
+        for opts in option_sets {
+            for poly in &polys {
+                let once = poly.normalized_with(opts);
+                assert_eq!(once.normalized_with(opts), once);
+            }
+            let mp = MultiPolygon(polys.clone());
+            let once = mp.normalized_with(opts);
+            assert_eq!(once.normalized_with(opts), once);
+
+            let gc = GeometryCollection(vec![
+                Geometry::MultiPolygon(mp),
+                Geometry::Polygon(polys[1].clone()),
+                Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(
+                    polys[4].clone(),
+                )])),
+            ]);
+            let once = gc.normalized_with(opts);
+            assert_eq!(once.normalized_with(opts), once);
+        }
+    }
+
+    #[test]
+    fn computes_ring_signed_area() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(ring_signed_area(good.exterior()), -2500.);
+        assert_eq!(ring_signed_area(bad.exterior()), 2500.);
+        assert_eq!(ring_signed_area(&good.interiors()[0]), 100.);
+
+        let open = LineString::from(vec![(0, 0), (4, 0), (4, 3)]);
+        assert_eq!(ring_signed_area(&open), 6);
+        assert_eq!(ring_signed_area(&LineString::<i32>::new(vec![])), 0);
+        let line = LineString::from(vec![(0., 0.), (1., 1.), (2., 2.), (0., 0.)]);
+        assert_eq!(ring_signed_area(&line), 0.);
+    }
+
+    #[test]
+    fn opposite_interior_only_fixes_holes() {
+        let opts = NormalizeOptions {
+            orientation: Orientation::OppositeInterior,
+            ..Default::default()
+        };
+        // Clockwise exterior, counter-clockwise holes
+        let (good, _) = get_bad_outer_bad_inner_poly();
+        let (_, cw_bad_hole) = get_good_outer_bad_inner_poly();
+        assert_eq!(good.normalized_with(opts), good);
+        assert_eq!(cw_bad_hole.normalized_with(opts), good);
+
+        // Counter-clockwise exterior, clockwise holes
+        let ccw = good.denormalized();
+        let mut ccw_bad_hole = ccw.clone();
+        ccw_bad_hole.interiors_mut(|rings| rings[0] = good.interiors()[0].clone());
+        assert_eq!(ccw.normalized_with(opts), ccw);
+        assert_eq!(ccw_bad_hole.normalized_with(opts), ccw);
+
+        // Without an exterior winding there is nothing to be opposite to
+        let flat = Polygon::new(
+            LineString::from(vec![(0., 0.), (5., 5.), (9., 9.), (0., 0.)]),
+            good.interiors().to_vec(),
+        );
+        assert_eq!(flat.normalized_with(opts), flat);
+    }
+
+    #[test]
+    fn crate_error_chains_its_source() {
+        use std::error::Error as _;
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        let err = Error::from(empty.try_normalized().unwrap_err());
+        assert_eq!(err.to_string(), "failed to normalize geometry");
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "polygon has an empty exterior ring");
+        assert_eq!(
+            source.downcast_ref::<NormalizeError>(),
+            Some(&NormalizeError::EmptyExterior)
+        );
+
+        fn normalize(poly: &Polygon<f64>) -> Result<Polygon<f64>, Error> {
+            Ok(poly.try_normalized()?)
+        }
+        assert_eq!(
+            normalize(&empty),
+            Err(Error::Normalize(NormalizeError::EmptyExterior))
+        );
+    }
+
+    #[test]
+    fn orientation_parses_and_displays_its_name() {
+        for orientation in [
+            Orientation::Ogc,
+            Orientation::GeoJson,
+            Orientation::Esri,
+            Orientation::Kml,
+            Orientation::D3,
+            Orientation::OppositeInterior,
+        ] {
+            assert_eq!(orientation.to_string().parse(), Ok(orientation));
+            assert_eq!(
+                orientation.to_string().to_uppercase().parse(),
+                Ok(orientation)
+            );
+        }
+        assert_eq!(Orientation::GeoJson.to_string(), "geojson");
+        assert_eq!(
+            "OppositeInterior".parse(),
+            Ok(Orientation::OppositeInterior)
+        );
+        assert_eq!(
+            " ogc".parse::<Orientation>(),
+            Err(ParseOrientationError {
+                input: " ogc".to_string()
+            })
+        );
+        let err = "right-hand".parse::<Orientation>().unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unknown orientation \"right-hand\""));
+        assert!(matches!(Error::from(err), Error::Orientation(_)));
+    }
+
+    #[test]
+    fn bbox_grid_is_reversed_in_place() {
+        let mut tiles = (0..100)
+            .map(|i| {
+                let (x, y) = (f64::from(i % 10), f64::from(i / 10));
+                Rect::new((x, y), (x + 1., y + 1.)).to_polygon()
+            })
+            .collect::<Vec<_>>();
+        let buffers = tiles
+            .iter()
+            .map(|tile| tile.exterior().0.as_ptr())
+            .collect::<Vec<_>>();
+        normalize_slice(&mut tiles);
+        for (tile, buffer) in tiles.iter().zip(buffers) {
+            assert!(tile.is_normalized());
+            assert_eq!(tile.exterior().0.len(), 5);
+            assert_eq!(tile.exterior().0.as_ptr(), buffer);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn normalized_checked_keeps_the_area() {
+        let opts = NormalizeOptions::builder()
+            .dedupe_consecutive(true)
+            .remove_collinear(true)
+            .remove_spikes(true)
+            .drop_degenerate_interiors(true)
+            .build();
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(bad.normalized_checked(opts), good);
+        let mp = MultiPolygon(vec![bad.clone(), good.clone()]);
+        assert_eq!(
+            mp.normalized_checked(opts),
+            MultiPolygon(vec![good.clone(), good])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    #[should_panic(expected = "normalization changed the area")]
+    fn normalized_checked_catches_area_changes() {
+        let opts = NormalizeOptions::builder()
+            .remove_collinear(true)
+            .collinear_tolerance(100.)
+            .build();
+        let bump = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 1.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        bump.normalized_checked(opts);
+    }
+
+    #[test]
+    fn can_normalize_into_multi_polygons() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(
+            bad.clone().into_normalized_multi(),
+            MultiPolygon(vec![good.clone()])
+        );
+        assert_eq!(
+            MultiPolygon(vec![bad.clone(), good.clone()]).into_normalized_multi(),
+            MultiPolygon(vec![good.clone(), good])
+        );
+    }
+
+    #[test]
+    fn normalized_geometries_are_always_normalized() {
+        let bowtie = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+        let collinear = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.), (0., 0.)]);
+        let line = LineString::from(vec![(0., 0.), (2., 0.)]);
+        let (square, _) = get_bad_outer_poly();
+        let mut polys = vec![Polygon::new(LineString::new(vec![]), vec![])];
+        for ring in [bowtie, collinear, line] {
+            polys.push(Polygon::new(ring.clone(), vec![]));
+            polys.push(Polygon::new(square.exterior().clone(), vec![ring]));
+        }
+        for (good, bad) in [
+            get_bad_outer_bad_inner_poly(),
+            get_bad_outer_good_inner_poly(),
+        ] {
+            polys.extend([good, bad]);
+        }
+        let flat = Triangle::new((0., 0.).into(), (1., 1.).into(), (2., 2.).into());
+        let gc = GeometryCollection(
+            polys
+                .iter()
+                .cloned()
+                .map(Geometry::Polygon)
+                .chain([Geometry::Triangle(flat)])
+                .collect(),
+        );
+
+        for poly in &polys {
+            assert!(poly.normalized().is_normalized(), "{:?}", poly);
+            assert!(matches!(
+                poly.normalized().normalized_cow(),
+                Cow::Borrowed(_)
+            ));
+        }
+        assert!(flat.is_normalized());
+        assert!(gc.normalized().is_normalized());
+        for orientation in [
+            Orientation::Ogc,
+            Orientation::GeoJson,
+            Orientation::Esri,
+            Orientation::Kml,
+            Orientation::D3,
+            Orientation::OppositeInterior,
+        ] {
+            let opts = NormalizeOptions::builder().orientation(orientation).build();
+            assert!(gc.normalized_with(opts).matches(orientation));
+        }
+    }
+
+    #[test]
+    fn zero_area_rings_are_left_as_they_are() {
+        let bowtie = LineString::from(vec![(0., 0.), (2., 2.), (2., 0.), (0., 2.), (0., 0.)]);
+        let collinear = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.), (0., 0.)]);
+        let (good, _) = get_bad_outer_poly();
+        for ring in [bowtie, collinear] {
+            let poly = Polygon::new(ring.clone(), vec![]);
+            assert_eq!(poly.normalized(), poly);
+            assert_eq!(poly.denormalized(), poly);
+            assert_eq!(poly.try_normalized(), Ok(poly.clone()));
+
+            let holed = Polygon::new(good.exterior().clone(), vec![ring]);
+            assert_eq!(holed.normalized(), holed);
+            let opts = NormalizeOptions::builder().reject_zero_area(true).build();
+            assert_eq!(
+                holed.try_normalized_with(opts),
+                Err(NormalizeError::ZeroArea { ring_index: 1 })
+            );
+        }
+    }
+
+    #[test]
+    fn try_normalized_rejects_too_many_vertices() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let opts = NormalizeOptions::builder().max_vertices(Some(10)).build();
+        assert_eq!(bad.try_normalized_with(opts), Ok(good.clone()));
+        let opts = NormalizeOptions::builder().max_vertices(Some(9)).build();
+        assert_eq!(
+            bad.try_normalized_with(opts),
+            Err(NormalizeError::TooManyVertices { count: 10 })
+        );
+        assert_eq!(bad.normalized_with(opts), good);
+        assert_eq!(bad.try_normalized(), Ok(good.clone()));
+
+        let (small, _) = get_bad_outer_poly();
+        assert_eq!(
+            MultiPolygon(vec![small.clone(), bad.clone()]).try_normalized_with(opts),
+            Err(NormalizeError::TooManyVertices { count: 10 })
+        );
+        assert!(MultiPolygon(vec![small.clone(), small])
+            .try_normalized_with(opts)
+            .is_ok());
+        assert!(GeometryCollection(vec![Geometry::Polygon(bad)])
+            .try_normalized_with(opts)
+            .is_err());
+    }
+
+    #[test]
+    fn try_normalized_rejects_coordinates_out_of_range() {
+        let opts = NormalizeOptions::builder()
+            .coordinate_range(Some(((-180., -90.).into(), (180., 90.).into())))
+            .build();
+        let lon_lat = polygon![
+            (x: -170.0, y: -80.0),
+            (x: 170.0, y: -80.0),
+            (x: 170.0, y: 80.0),
+            (x: -170.0, y: 80.0),
+        ];
+        assert_eq!(lon_lat.try_normalized_with(opts), Ok(lon_lat.normalized()));
+
+        // Latitude and longitude swapped in the second coordinate of the hole, which is
+        // reported by its position before the hole is reversed
+        let swapped = Polygon::new(
+            lon_lat.exterior().clone(),
+            vec![LineString::from(vec![
+                (0., 0.),
+                (0., 100.),
+                (10., 10.),
+                (0., 0.),
+            ])],
+        );
+        assert_eq!(
+            swapped.try_normalized_with(opts),
+            Err(NormalizeError::OutOfRange {
+                ring_index: 1,
+                point_index: 1,
+            })
+        );
+        assert!(swapped.try_normalized().is_ok());
+        assert_eq!(swapped.normalized_with(opts), swapped.normalized());
+
+        let tri = Triangle::new((0., 0.).into(), (200., 0.).into(), (0., 1.).into());
+        assert_eq!(
+            tri.try_normalized_with(opts),
+            Err(NormalizeError::OutOfRange {
+                ring_index: 0,
+                point_index: 1,
+            })
+        );
+        let mp = MultiPolygon(vec![lon_lat, swapped]);
+        assert!(matches!(
+            mp.try_normalized_with(opts),
+            Err(NormalizeError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn kml_winds_exteriors_counter_clockwise() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let kml = normalize_for_kml(&good);
+        assert_eq!(kml, bad);
+        assert_eq!(exterior_winding(&kml), Some(WindingOrder::CounterClockwise));
+        assert_eq!(interior_windings(&kml), vec![Some(WindingOrder::Clockwise)]);
+        assert_eq!(
+            normalize_for_kml(&MultiPolygon(vec![good])),
+            MultiPolygon(vec![bad])
+        );
+    }
+
+    #[test]
+    fn tallies_ring_windings() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (_, bad_outer) = get_bad_outer_poly();
+        let mp = MultiPolygon(vec![good.clone(), bad.clone(), bad_outer]);
+        assert_eq!(
+            mp.winding_histogram(),
+            WindingStats {
+                cw_exteriors: 1,
+                ccw_exteriors: 2,
+                cw_interiors: 1,
+                ccw_interiors: 1,
+                undefined: 0,
+            }
+        );
+
+        let flat = Polygon::new(LineString::from(vec![(0., 0.), (1., 1.)]), vec![]);
+        let gc = GeometryCollection(vec![
+            Geometry::MultiPolygon(mp.clone()),
+            Geometry::Point(Point::new(1., 1.)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(flat)])),
+        ]);
+        let stats = gc.winding_histogram();
+        assert_eq!(stats.ccw_exteriors, 2);
+        assert_eq!(stats.undefined, 1);
+        assert_eq!(mp.normalized().winding_histogram().ccw_exteriors, 0);
+        assert_eq!(good.winding_histogram().cw_exteriors, 1);
+    }
+
+    #[test]
+    fn can_normalize_through_trait_objects() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let mut registry: Vec<Box<dyn NormalizeDyn<f64>>> = vec![
+            Box::new(bad.clone()),
+            Box::new(MultiPolygon(vec![bad.clone()])),
+            Box::new(Point::new(1., 1.)),
+        ];
+        assert!(!registry[0].is_normalized_dyn());
+        let norm = registry[1].normalized_boxed();
+        assert_eq!(
+            norm.as_any().downcast_ref::<MultiPolygon<f64>>(),
+            Some(&MultiPolygon(vec![good.clone()]))
+        );
+        for geom in registry.iter_mut() {
+            geom.normalize_dyn();
+            assert!(geom.is_normalized_dyn());
+        }
+        assert_eq!(
+            registry[0].as_any().downcast_ref::<Polygon<f64>>(),
+            Some(&good)
+        );
+        assert_eq!(normalize_geometry(&bad.into()), Geometry::Polygon(good));
+    }
+
+    #[test]
+    fn compares_normalized_polygons_within_epsilon() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let mut noisy = bad.clone();
+        noisy.exterior_mut(|ring| {
+            ring.0.iter_mut().for_each(|c| c.x += 1e-9);
+            ring.0.rotate_left(1);
+            ring.0[4] = ring.0[0];
+        });
+        assert!(!noisy.normalized().approx_eq(&good, 0.));
+        assert!(noisy.normalized().approx_eq(&good, 1e-6));
+        assert!(!noisy.normalized().approx_eq(&good, 1e-12));
+        assert!(!bad.approx_eq(&good, 1e-6));
+
+        let no_holes = Polygon::new(good.exterior().clone(), vec![]);
+        assert!(!no_holes.approx_eq(&good, 1e-6));
+        assert!(MultiPolygon(vec![good.clone()])
+            .approx_eq(&MultiPolygon(vec![noisy.normalized()]), 1e-6));
+        assert!(!MultiPolygon(vec![good.clone()]).approx_eq(&MultiPolygon(vec![]), 1e-6));
+        assert!(LineString::<i32>::new(vec![]).approx_eq(&LineString::new(vec![]), 0));
+    }
+
+    #[test]
+    fn normalizes_triangles_in_collections() {
+        let (good, bad) = get_bad_outer_poly();
+        let ccw = Triangle::new((0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 4.0).into());
+        let line = Line::new((0.0, 0.0), (1.0, 1.0));
+        let rect = Rect::new((0.0, 0.0), (1.0, 1.0));
+        let gc = GeometryCollection(vec![
+            Geometry::Triangle(ccw),
+            Geometry::Polygon(bad),
+            Geometry::Line(line),
+            Geometry::Rect(rect),
+        ]);
+        assert!(!gc.is_normalized());
+        assert!(!Geometry::Triangle(ccw).is_normalized());
+
+        let norm = gc.normalized();
+        assert!(norm.is_normalized());
+        assert_eq!(norm.0[0], Geometry::Triangle(ccw.normalized()));
+        assert_ne!(norm.0[0], Geometry::Triangle(ccw));
+        assert_eq!(norm.0[1], Geometry::Polygon(good));
+        assert_eq!(norm.0[2], Geometry::Line(line));
+        assert_eq!(norm.0[3], Geometry::Rect(rect));
+        assert_eq!(gc.try_normalized().unwrap(), norm);
+    }
+
+    #[test]
+    fn only_reorders_coordinates() {
+        fn bits(ring: &LineString<f64>) -> Vec<(u64, u64)> {
+            let mut bits: Vec<_> = ring
+                .coords()
+                .map(|c| (c.x.to_bits(), c.y.to_bits()))
+                .collect();
+            bits.sort_unstable();
+            bits
+        }
+        // Values that do not survive arithmetic round trips unchanged
+        let exterior = LineString::from(vec![
+            (-0.0, 0.1 + 0.2),
+            (1e300, f64::MIN_POSITIVE / 4.),
+            (1e300, 1e300),
+            (0.1 + 0.2, 1e300 + 1.),
+            (-0.0, 0.1 + 0.2),
+        ]);
+        let interior = LineString::from(vec![
+            (1.0, 1.0 + f64::EPSILON),
+            (1e10 / 3., 1.0 + f64::EPSILON),
+            (1.0, 1e10 / 3.),
+            (1.0, 1.0 + f64::EPSILON),
+        ]);
+        let poly = Polygon::new(exterior.clone(), vec![interior.clone()]);
+        let opts = NormalizeOptions::builder()
+            .canonical_start(true)
+            .sort_interiors(true)
+            .build();
+        for norm in [poly.normalized(), poly.normalized_with(opts)] {
+            assert_ne!(norm, poly);
+            assert_eq!(bits(norm.exterior()), bits(&exterior));
+            assert_eq!(bits(&norm.interiors()[0]), bits(&interior));
+        }
+
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(poly.clone()),
+            Geometry::LineString(exterior.clone()),
+        ]);
+        let norm = gc.normalized();
+        assert_eq!(norm.0[0], Geometry::Polygon(poly.normalized()));
+        assert_eq!(norm.0[1], Geometry::LineString(exterior));
+    }
+
+    proptest::prop_compose! {
+        /// The offsets from its centre of a simple, star shaped ring of at least `points`
+        /// vertices, with radii between `min` and `max`, wound either way
+        fn star_ring(min: f64, max: f64, points: usize)(
+            vertices in proptest::collection::vec((0.0..0.9, 0.0..1.0), points..points + 16),
+            reversed: bool,
+        ) -> Vec<Coord<f64>> {
+            let count = vertices.len() as f64;
+            let mut ring: Vec<_> = vertices
+                .into_iter()
+                .enumerate()
+                .map(|(i, (jitter, r)): (usize, (f64, f64))| {
+                    let angle = core::f64::consts::TAU * (i as f64 + jitter) / count;
+                    let radius = min + (max - min) * r;
+                    geo::coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+                })
+                .collect();
+            if reversed {
+                ring.reverse();
+            }
+            ring
+        }
+    }
+
+    proptest::prop_compose! {
+        /// A polygon with a star shaped exterior and up to four star shaped holes, each wound
+        /// at random
+        fn random_polygon()(
+            cx in -5e3..5e3,
+            cy in -5e3..5e3,
+            exterior in star_ring(80., 100., 8),
+            holes in proptest::collection::vec(star_ring(2., 8., 3), 0..5),
+        ) -> Polygon<f64> {
+            let centre = geo::coord! { x: cx, y: cy };
+            let ring = |offsets: Vec<Coord<f64>>, at: Coord<f64>| -> LineString<f64> {
+                offsets.into_iter().map(|c| c + at).collect()
+            };
+            // Holes are kept apart, on a circle well inside the smallest exterior radius
+            let interiors = holes
+                .into_iter()
+                .enumerate()
+                .map(|(i, hole)| {
+                    let angle = core::f64::consts::TAU * i as f64 / 5.;
+                    ring(hole, centre + geo::coord! { x: 30. * angle.cos(), y: 30. * angle.sin() })
+                })
+                .collect();
+            Polygon::new(ring(exterior, centre), interiors)
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn random_exteriors_normalize_clockwise(poly in random_polygon()) {
+            proptest::prop_assert!(poly.normalized().exterior().is_cw());
+        }
+
+        #[test]
+        fn random_interiors_normalize_counter_clockwise(poly in random_polygon()) {
+            let norm = poly.normalized();
+            proptest::prop_assert!(norm.interiors().iter().all(|ring| ring.is_ccw()));
+        }
+
+        #[test]
+        fn random_polygons_keep_their_area(poly in random_polygon()) {
+            let norm = poly.normalized();
+            let (before, after) = (poly.unsigned_area(), norm.unsigned_area());
+            proptest::prop_assert!(
+                (before - after).abs() <= before * 1e-9,
+                "area {} became {}",
+                before,
+                after
+            );
+            proptest::prop_assert!(norm.is_normalized());
+            proptest::prop_assert_eq!(norm.normalized(), norm);
+        }
+    }
+
+    #[test]
+    fn normalized_keys_match_canonical_shapes() {
+        use std::collections::HashMap;
+
+        let to_int = |poly: &Polygon<f64>| {
+            let ring = |ring: &LineString<f64>| -> LineString<i64> {
+                ring.coords().map(|c| (c.x as i64, c.y as i64)).collect()
+            };
+            Polygon::new(
+                ring(poly.exterior()),
+                poly.interiors().iter().map(ring).collect(),
+            )
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (_, other) = get_bad_outer_poly();
+        let mut rotated = to_int(&good);
+        rotated.exterior_mut(|ring| {
+            ring.0.pop();
+            ring.0.rotate_left(2);
+            ring.close();
+        });
+
+        let mut counts = HashMap::new();
+        for poly in [to_int(&good), to_int(&bad), rotated, to_int(&other)] {
+            *counts.entry(NormalizedKey::new(&poly)).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&NormalizedKey::new(&to_int(&bad))], 3);
+
+        let mut noisy = bad.clone();
+        noisy.exterior_mut(|ring| ring.0.iter_mut().for_each(|c| c.x += 1e-9));
+        assert_eq!(
+            NormalizedKey::from_rounded(&noisy, 6),
+            NormalizedKey::from_rounded(&good, 6)
+        );
+        assert_ne!(
+            NormalizedKey::from_rounded(&noisy, 12),
+            NormalizedKey::from_rounded(&good, 12)
+        );
+        let negative_zero = Polygon::new(
+            LineString::from(vec![(-0.0, -0.0), (0.0, 1.0), (1.0, 0.0)]),
+            vec![],
+        );
+        let zero = Polygon::new(
+            LineString::from(vec![(0.0, 0.0), (0.0, 1.0), (1.0, 0.0)]),
+            vec![],
+        );
+        assert_eq!(
+            NormalizedKey::from_rounded(&negative_zero, 3),
+            NormalizedKey::from_rounded(&zero, 3)
+        );
+    }
+
+    #[test]
+    fn normalize_interiors_keeps_the_exterior() {
+        // A counter-clockwise exterior with a counter-clockwise hole
+        let (good, bad) = get_bad_outer_good_inner_poly();
+        let norm = bad.normalize_interiors();
+        assert_eq!(norm.exterior(), bad.exterior());
+        assert!(norm.exterior().is_ccw());
+        assert!(norm.interiors().iter().all(|ring| ring.is_cw()));
+        assert_eq!(norm.normalize_interiors(), norm);
+        assert_eq!(good.normalize_interiors(), good);
+
+        let mp = MultiPolygon(vec![good.clone(), bad]);
+        assert_eq!(mp.normalize_interiors(), MultiPolygon(vec![good, norm]));
+    }
+
+    #[test]
+    fn normalize_mut_reverses_rings_within_their_buffers() {
+        let (good, mut poly) = get_bad_outer_bad_inner_poly();
+        let exterior = poly.exterior().0.as_ptr();
+        let interiors = poly.interiors().as_ptr();
+        let interior = poly.interiors()[0].0.as_ptr();
+        poly.normalize_mut();
+        assert_eq!(poly, good);
+        assert_eq!(poly.exterior().0.as_ptr(), exterior);
+        assert_eq!(poly.interiors().as_ptr(), interiors);
+        assert_eq!(poly.interiors()[0].0.as_ptr(), interior);
+        for ring in core::iter::once(poly.exterior()).chain(poly.interiors()) {
+            assert_eq!(ring.0.first(), ring.0.last());
+        }
+
+        let mut open = LineString::from(vec![(0., 0.), (5., 0.), (5., 5.)]);
+        assert!(wind_ring(&mut open, WindingOrder::Clockwise));
+        assert_eq!(
+            open,
+            LineString::from(vec![(0., 0.), (5., 5.), (5., 0.), (0., 0.)])
+        );
+        let mut flat = LineString::from(vec![(0., 0.), (5., 0.), (0., 0.)]);
+        assert!(!wind_ring(&mut flat, WindingOrder::Clockwise));
+    }
+
+    #[test]
+    fn normalize_ring_matches_the_polygon_path() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let exterior = normalize_ring(bad.exterior(), WindingOrder::Clockwise);
+        let interior = normalize_ring(&bad.interiors()[0], WindingOrder::CounterClockwise);
+        assert_eq!(Polygon::new(exterior, vec![interior]), good);
+        assert_eq!(
+            normalize_ring(good.exterior(), WindingOrder::Clockwise),
+            *good.exterior()
+        );
+
+        let open = LineString::from(vec![(0., 0.), (5., 0.), (5., 5.)]);
+        assert_eq!(
+            normalize_ring(&open, WindingOrder::CounterClockwise),
+            LineString::from(vec![(0., 0.), (5., 0.), (5., 5.), (0., 0.)])
+        );
+    }
+
+    #[test]
+    fn normalized_with_area_matches_geo_area() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let (_, bad_outer) = get_bad_outer_poly();
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        for poly in [good, bad, bad_outer, empty] {
+            let (norm, area) = normalized_with_area(&poly);
+            assert_eq!(norm, poly.normalized());
+            assert_eq!(area, poly.unsigned_area());
+            assert_eq!(area, norm.unsigned_area());
+        }
+
+        let ints: Polygon<i64> = polygon![
+            (x: 0, y: 0),
+            (x: 3, y: 0),
+            (x: 0, y: 3),
+        ];
+        let (norm, area) = normalized_with_area(&ints);
+        assert!(norm.is_normalized());
+        assert_eq!(area, 4);
+    }
+
+    #[test]
+    fn normalized_with_area_matches_normalized_with() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        // A figure of eight with lobes of different sizes: geo finds it counter-clockwise at its
+        // lowest vertex, while its signed area is negative
+        let crossing = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 4.), (4., 0.), (0., 2.), (0., 0.)]),
+            vec![],
+        );
+        assert_eq!(
+            ring::winding_order(crossing.exterior()),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert!(ring_signed_area(crossing.exterior()) < 0.);
+        let orientations = [
+            Orientation::Ogc,
+            Orientation::GeoJson,
+            Orientation::Esri,
+            Orientation::Kml,
+            Orientation::D3,
+            Orientation::OppositeInterior,
+        ];
+        for poly in [good, bad, crossing] {
+            for orientation in orientations {
+                for y_axis in [YAxis::Up, YAxis::Down] {
+                    let opts = NormalizeOptions {
+                        orientation,
+                        y_axis,
+                        ..Default::default()
+                    };
+                    let (norm, area) = normalized_with_area_with(&poly, opts);
+                    assert_eq!(norm, poly.normalized_with(opts));
+                    assert_eq!(area, poly.unsigned_area());
+                }
+            }
+        }
+
+        // Rings dropped by the options are not counted
+        let (square, _) = get_bad_outer_poly();
+        let copied = Polygon::new(square.exterior().clone(), vec![square.exterior().clone()]);
+        let opts = NormalizeOptions {
+            drop_exterior_copies: true,
+            ..Default::default()
+        };
+        let (norm, area) = normalized_with_area_with(&copied, opts);
+        assert_eq!(norm, copied.normalized_with(opts));
+        assert_eq!(area, 9.);
+
+        // A collinear ring has no winding and is only closed
+        let flat = Polygon::new(LineString::from(vec![(0., 0.), (1., 1.), (2., 2.)]), vec![]);
+        let (norm, area) = normalized_with_area(&flat);
+        assert_eq!(norm, flat);
+        assert_eq!(area, 0.);
+    }
+
+    #[test]
+    fn tracks_close_into_clockwise_polygons() {
+        let track = vec![(0., 0.), (5., 0.), (5., 5.), (0., 5.)];
+        let poly = track_to_polygon(track.clone());
+        assert_eq!(
+            poly,
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (0., 5.), (5., 5.), (5., 0.), (0., 0.)]),
+                vec![]
+            )
+        );
+        assert!(poly.is_normalized());
+        let reversed = track_to_polygon(track.into_iter().rev());
+        assert!(reversed.is_normalized());
+        assert!(reversed.approx_eq(&poly, 0.));
+
+        let closed: Vec<Coord<i64>> =
+            vec![(0, 0).into(), (4, 0).into(), (0, 4).into(), (0, 0).into()];
+        assert_eq!(track_to_polygon(closed.clone()).exterior().0.len(), 4);
+        assert!(track_to_polygon(Vec::<Coord<f64>>::new())
+            .exterior()
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn reverse_rings_always_reverses() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let mut poly = good.clone();
+        poly.reverse_exterior();
+        poly.reverse_interiors();
+        assert_eq!(poly, bad);
+        poly.reverse_interiors();
+        assert_eq!(
+            exterior_winding(&poly),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert_eq!(
+            interior_windings(&poly),
+            vec![Some(WindingOrder::CounterClockwise)]
+        );
+        for ring in core::iter::once(poly.exterior()).chain(poly.interiors()) {
+            assert!(ring.is_closed());
+        }
+        poly.reverse_exterior();
+        assert_eq!(poly, good);
+
+        let mut empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        empty.reverse_exterior();
+        empty.reverse_interiors();
+        assert!(empty.exterior().0.is_empty());
+    }
+
+    #[test]
+    fn matches_checks_every_ring_against_the_orientation() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let geojson = bad.normalized_with(NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        });
+        assert!(good.matches(Orientation::Ogc));
+        assert!(good.matches(Orientation::Esri));
+        assert!(!good.matches(Orientation::GeoJson));
+        assert!(geojson.matches(Orientation::GeoJson));
+        assert!(geojson.matches(Orientation::Kml));
+        assert!(geojson.matches(Orientation::D3));
+        assert!(good.matches(Orientation::OppositeInterior));
+        assert!(geojson.matches(Orientation::OppositeInterior));
+        // Counter-clockwise exterior and interior rings match no orientation
+        let (_, same) = get_bad_outer_good_inner_poly();
+        assert!(!same.matches(Orientation::Ogc));
+        assert!(!same.matches(Orientation::GeoJson));
+        assert!(!same.matches(Orientation::OppositeInterior));
+
+        let (_, bad_outer) = get_bad_outer_poly();
+        let mp = MultiPolygon(vec![geojson.clone(), bad_outer]);
+        assert!(mp.matches(Orientation::GeoJson));
+        assert!(!mp.matches(Orientation::Ogc));
+        let gc = GeometryCollection(vec![
+            Geometry::MultiPolygon(mp),
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::GeometryCollection(GeometryCollection(vec![Geometry::Polygon(good)])),
+        ]);
+        assert!(!gc.matches(Orientation::GeoJson));
+        assert!(gc.matches(Orientation::OppositeInterior));
     }
 
     #[test]
@@ -160,7 +3767,7 @@ mod tests {
         }
     }
 
-    fn get_bad_outer_poly() -> (Polygon<f64>, Polygon<f64>) {
+    pub(crate) fn get_bad_outer_poly() -> (Polygon<f64>, Polygon<f64>) {
         let bad = polygon![
         (x: 1.0, y: 1.0),
         (x: 4.0, y: 1.0),
@@ -178,7 +3785,7 @@ mod tests {
         (good, bad)
     }
 
-    fn get_good_outer_bad_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
+    pub(crate) fn get_good_outer_bad_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
         let bad = polygon!(
             exterior: [
                 (x: 0., y: 0.),
@@ -214,7 +3821,7 @@ mod tests {
         (good, bad)
     }
 
-    fn get_bad_outer_bad_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
+    pub(crate) fn get_bad_outer_bad_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
         let bad = polygon!(
             exterior: [
                 (x: 0., y: 0.),
@@ -250,7 +3857,7 @@ mod tests {
         (good, bad)
     }
 
-    fn get_bad_outer_good_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
+    pub(crate) fn get_bad_outer_good_inner_poly() -> (Polygon<f64>, Polygon<f64>) {
         let bad = polygon!(
             exterior: [
                 (x: 0., y: 0.),
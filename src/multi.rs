@@ -0,0 +1,44 @@
+use crate::Normalized;
+use alloc::vec;
+use geo_types::{CoordNum, MultiPolygon, Polygon};
+
+/// Normalize a polygon or multipolygon into a `MultiPolygon`, for code that always handles
+/// multipolygons
+pub trait IntoNormalizedMulti<T: CoordNum> {
+    /// Normalize with the OGC winding rules and return the result as a `MultiPolygon`
+    ///
+    /// A `Polygon` becomes a multipolygon with a single member, a `MultiPolygon` is only
+    /// normalized. Like [`Normalized::into_normalized`] this reuses the allocations of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, MultiPolygon};
+    /// use geo_normalized2::{IntoNormalizedMulti, Normalized};
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert_eq!(
+    ///     poly.clone().into_normalized_multi(),
+    ///     MultiPolygon(vec![poly.normalized()])
+    /// );
+    /// ```
+    fn into_normalized_multi(self) -> MultiPolygon<T>;
+}
+
+impl<T: CoordNum> IntoNormalizedMulti<T> for Polygon<T> {
+    fn into_normalized_multi(self) -> MultiPolygon<T> {
+        MultiPolygon(vec![self.into_normalized()])
+    }
+}
+
+impl<T: CoordNum> IntoNormalizedMulti<T> for MultiPolygon<T> {
+    fn into_normalized_multi(self) -> MultiPolygon<T> {
+        self.into_normalized()
+    }
+}
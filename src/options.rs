@@ -0,0 +1,496 @@
+use crate::{ParseOrientationError, WindingOrder};
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+use geo_types::{Coord, CoordNum};
+
+/// The winding convention that normalization should produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Orientation {
+    /// OGC simple features: clockwise exterior rings and counter-clockwise interior rings
+    #[default]
+    Ogc,
+    /// GeoJSON RFC 7946 (right-hand rule): counter-clockwise exterior rings and clockwise
+    /// interior rings
+    GeoJson,
+    /// ESRI shapefile: clockwise exterior rings and counter-clockwise interior rings, as
+    /// required by the shapefile specification. This currently winds rings the same way as
+    /// [`Orientation::Ogc`], but names the convention for code that targets shapefiles.
+    Esri,
+    /// KML: counter-clockwise exterior rings and clockwise interior rings, the right-hand rule
+    /// that Google Earth expects for `<Polygon>` boundaries. This winds rings the same way as
+    /// [`Orientation::GeoJson`].
+    Kml,
+    /// 3D engines such as CesiumJS: counter-clockwise exterior rings and clockwise interior
+    /// rings, so that, seen from above, the front face of a polygon points up and out of the
+    /// globe. This winds rings the same way as [`Orientation::GeoJson`], but names the
+    /// convention for code that feeds 3D renderers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{NormalizeOptions, Normalized, Orientation};
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// let opts = NormalizeOptions {
+    ///     orientation: Orientation::D3,
+    ///     ..Default::default()
+    /// };
+    /// let norm = poly.normalized_with(opts);
+    /// let coords: Vec<(f64, f64)> = norm.exterior().coords().map(|c| (c.x, c.y)).collect();
+    /// assert_eq!(coords, vec![(1.0, 1.0), (4.0, 1.0), (4.0, 4.0), (1.0, 4.0), (1.0, 1.0)]);
+    /// ```
+    D3,
+    /// Only fix the winding of interior rings relative to their exterior: the exterior ring is
+    /// left as it is, whichever way it winds, and interior rings are reversed when they wind the
+    /// same way as the exterior. When the exterior has no defined winding, because it is
+    /// degenerate, the interior rings are left untouched too.
+    OppositeInterior,
+}
+
+impl Orientation {
+    /// The lowercase name of the orientation, as written by `Display` and `serde`
+    fn name(&self) -> &'static str {
+        match self {
+            Orientation::Ogc => "ogc",
+            Orientation::GeoJson => "geojson",
+            Orientation::Esri => "esri",
+            Orientation::Kml => "kml",
+            Orientation::D3 => "d3",
+            Orientation::OppositeInterior => "oppositeinterior",
+        }
+    }
+}
+
+/// Writes the lowercase name of the orientation, the same name used by `serde`, which
+/// [`FromStr`] parses back
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of an orientation, ignoring ASCII case, such as `"ogc"` or `"GeoJSON"`
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::Orientation;
+///
+/// assert_eq!("GeoJSON".parse(), Ok(Orientation::GeoJson));
+/// assert_eq!(Orientation::Esri.to_string().parse(), Ok(Orientation::Esri));
+/// assert!("ccw".parse::<Orientation>().is_err());
+/// ```
+impl FromStr for Orientation {
+    type Err = ParseOrientationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Orientation::Ogc,
+            Orientation::GeoJson,
+            Orientation::Esri,
+            Orientation::Kml,
+            Orientation::D3,
+            Orientation::OppositeInterior,
+        ]
+        .iter()
+        .copied()
+        .find(|orientation| orientation.name().eq_ignore_ascii_case(s))
+        .ok_or_else(|| ParseOrientationError {
+            input: s.to_string(),
+        })
+    }
+}
+
+/// The direction of the `y` axis of the coordinates, see [`NormalizeOptions::y_axis`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum YAxis {
+    /// `y` grows upwards, as in geographic and mathematical coordinates
+    #[default]
+    Up,
+    /// `y` grows downwards, as in screen, canvas and image pixel coordinates
+    Down,
+}
+
+impl YAxis {
+    /// The winding order, in `y` up terms, of a ring that appears wound in `winding` order on
+    /// this axis
+    pub(crate) fn apply(self, winding: Option<WindingOrder>) -> Option<WindingOrder> {
+        match self {
+            YAxis::Up => winding,
+            YAxis::Down => winding.map(|winding| match winding {
+                WindingOrder::Clockwise => WindingOrder::CounterClockwise,
+                WindingOrder::CounterClockwise => WindingOrder::Clockwise,
+            }),
+        }
+    }
+}
+
+impl Orientation {
+    /// The winding order required for exterior rings, or `None` when the orientation leaves
+    /// exterior rings as they are
+    pub fn exterior_winding(&self) -> Option<WindingOrder> {
+        match self {
+            Orientation::Ogc | Orientation::Esri => Some(WindingOrder::Clockwise),
+            Orientation::GeoJson | Orientation::Kml | Orientation::D3 => {
+                Some(WindingOrder::CounterClockwise)
+            }
+            Orientation::OppositeInterior => None,
+        }
+    }
+
+    /// The winding order required for interior rings, or `None` when it depends on the winding
+    /// of the exterior ring, see [`Orientation::OppositeInterior`]
+    pub fn interior_winding(&self) -> Option<WindingOrder> {
+        match self {
+            Orientation::Ogc | Orientation::Esri => Some(WindingOrder::CounterClockwise),
+            Orientation::GeoJson | Orientation::Kml | Orientation::D3 => {
+                Some(WindingOrder::Clockwise)
+            }
+            Orientation::OppositeInterior => None,
+        }
+    }
+
+    /// The winding order required for the interior rings of a polygon whose exterior ring ends
+    /// up wound in the `exterior` order
+    pub(crate) fn interior_winding_within(
+        &self,
+        exterior: Option<WindingOrder>,
+    ) -> Option<WindingOrder> {
+        self.interior_winding().or_else(|| {
+            exterior.map(|winding| match winding {
+                WindingOrder::Clockwise => WindingOrder::CounterClockwise,
+                WindingOrder::CounterClockwise => WindingOrder::Clockwise,
+            })
+        })
+    }
+}
+
+/// Options controlling how a geometry is normalized
+///
+/// The default options produce the OGC winding used by [`Normalized::normalized`].
+///
+/// [`Normalized::normalized`]: crate::Normalized::normalized
+///
+/// With the `serde` feature the options can be deserialized from configuration files; any
+/// missing field takes its default value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, bound(deserialize = "T: CoordNum + serde::Deserialize<'de>"))
+)]
+pub struct NormalizeOptions<T: CoordNum> {
+    /// The winding convention of the output rings
+    pub orientation: Orientation,
+    /// Rotate every ring so that it starts at its lexicographically smallest coordinate
+    /// (smallest `x`, then smallest `y`), so the same shape always normalizes to equal rings
+    /// whatever vertex it originally started at
+    pub canonical_start: bool,
+    /// Remove consecutive duplicate coordinates from every ring before computing its winding,
+    /// comparing coordinates with exact equality. The ring stays closed afterwards.
+    pub dedupe_consecutive: bool,
+    /// Remove vertices that lie on a straight line between their neighbours. A vertex `b`
+    /// between `a` and `c` is removed when the cross product of `b - a` and `c - b` is within
+    /// `collinear_tolerance` of zero and the ring keeps going in the same direction through `b`.
+    /// Vertices where the ring turns back on itself are not touched.
+    pub remove_collinear: bool,
+    /// Remove spikes, where the ring goes out to a vertex and turns straight back along the
+    /// same line. The tip of the spike is removed when the cross product of the edges into and
+    /// out of it is within `collinear_tolerance` of zero and they point in opposite directions.
+    /// Spikes are removed before collinear vertices.
+    pub remove_spikes: bool,
+    /// The largest absolute cross product still treated as collinear by `remove_collinear` and
+    /// `remove_spikes`. Defaults to zero, so only exactly collinear vertices and exact
+    /// back-tracks are removed.
+    pub collinear_tolerance: T,
+    /// Sort the interior rings of every polygon, so that polygons listing the same holes in a
+    /// different order normalize to equal values. Rings are ordered by their lexicographically
+    /// smallest coordinate (smallest `x`, then smallest `y`) and then by their number of
+    /// coordinates; the sort is stable, so rings with equal keys keep their relative order.
+    pub sort_interiors: bool,
+    /// Sort the polygons of every multipolygon, so that multipolygons listing the same polygons
+    /// in a different order normalize to equal values. Polygons are ordered by their exterior
+    /// ring, using the same key as `sort_interiors`; the sort is stable.
+    pub sort_polygons: bool,
+    /// Remove the polygons with an empty exterior ring, such as the slivers left by clipping,
+    /// from every multipolygon. Otherwise they are copied through unchanged by the infallible
+    /// methods, and make [`Normalized::try_normalized_with`] fail with
+    /// [`NormalizeError::EmptyExterior`].
+    ///
+    /// [`Normalized::try_normalized_with`]: crate::Normalized::try_normalized_with
+    /// [`NormalizeError::EmptyExterior`]: crate::NormalizeError::EmptyExterior
+    pub drop_empty: bool,
+    /// Remove interior rings whose signed area is exactly zero, such as holes that collapsed to
+    /// a line. Exterior rings are never removed.
+    pub drop_degenerate_interiors: bool,
+    /// Remove interior rings that are identical to an earlier interior ring of the same
+    /// polygon, keeping the first. Rings are compared after they have been wound and cleaned up,
+    /// and regardless of the vertex they start at, so the same hole written from a different
+    /// start vertex is still removed. Coordinates are compared with exact equality.
+    pub dedupe_interiors: bool,
+    /// Remove interior rings that trace the same boundary as the exterior ring, in either
+    /// direction and from any start vertex, as left behind by buggy buffering code. Such a hole
+    /// cancels out its polygon to a zero area, and winding it opposite to the exterior does not
+    /// make the polygon valid, see [`OgcViolation::InteriorEqualsExterior`]. Coordinates are
+    /// compared with exact equality.
+    ///
+    /// [`OgcViolation::InteriorEqualsExterior`]: crate::OgcViolation::InteriorEqualsExterior
+    pub drop_exterior_copies: bool,
+    /// Round every coordinate to this many decimal places before any other step, so that
+    /// coordinates that only differ by floating point noise become equal. Rounding happens
+    /// before the winding is computed, but it can, in rare cases, collapse a small ring to a
+    /// degenerate one whose winding is undefined. Integer coordinates are left as they are.
+    pub round_decimals: Option<u32>,
+    /// Make [`Normalized::try_normalized_with`] fail with [`NormalizeError::ZeroArea`] for rings
+    /// whose signed area is exactly zero. Such rings have no defined winding, so they are
+    /// otherwise left exactly as they are.
+    ///
+    /// [`Normalized::try_normalized_with`]: crate::Normalized::try_normalized_with
+    /// [`NormalizeError::ZeroArea`]: crate::NormalizeError::ZeroArea
+    pub reject_zero_area: bool,
+    /// The smallest and largest allowed coordinates, inclusive, such as `(-180, -90)` and
+    /// `(180, 90)` for longitude and latitude. [`Normalized::try_normalized_with`] fails with
+    /// [`NormalizeError::OutOfRange`] for any coordinate outside of it, which catches swapped
+    /// axes or projected coordinates. The check runs before any ring is changed, so the error
+    /// gives the position of the coordinate in the input. Off by default; the infallible
+    /// methods never check it.
+    ///
+    /// [`Normalized::try_normalized_with`]: crate::Normalized::try_normalized_with
+    /// [`NormalizeError::OutOfRange`]: crate::NormalizeError::OutOfRange
+    pub coordinate_range: Option<(Coord<T>, Coord<T>)>,
+    /// The largest number of coordinates a polygon may have, counted across all of its rings,
+    /// closing coordinates included. [`Normalized::try_normalized_with`] fails with
+    /// [`NormalizeError::TooManyVertices`] for larger polygons before anything is copied or
+    /// changed, which guards against huge polygons from untrusted input. Each polygon of a
+    /// multipolygon or collection is limited on its own. Off by default; the infallible methods
+    /// never check it.
+    ///
+    /// [`Normalized::try_normalized_with`]: crate::Normalized::try_normalized_with
+    /// [`NormalizeError::TooManyVertices`]: crate::NormalizeError::TooManyVertices
+    pub max_vertices: Option<usize>,
+    /// The direction of the `y` axis. The winding of a ring is found as geo's
+    /// `Winding::winding_order` finds it, from the turn the ring makes at its lexicographically
+    /// smallest vertex, in terms of `y` growing upwards; rings with a signed area of exactly
+    /// zero are left as they are. When `y` grows downwards, as in pixel coordinates, a ring
+    /// appears mirrored on screen, so with [`YAxis::Down`] the winding required by
+    /// `orientation` is flipped before it is compared with the ring's: a clockwise exterior ring
+    /// on screen is one geo finds counter-clockwise. Relative windings, as for
+    /// [`Orientation::OppositeInterior`], are the same on both axes. Checks such as
+    /// [`Normalized::is_normalized`] always assume [`YAxis::Up`].
+    ///
+    /// [`Normalized::is_normalized`]: crate::Normalized::is_normalized
+    pub y_axis: YAxis,
+}
+
+impl<T: CoordNum> Default for NormalizeOptions<T> {
+    fn default() -> Self {
+        NormalizeOptions {
+            orientation: Orientation::default(),
+            canonical_start: false,
+            dedupe_consecutive: false,
+            remove_collinear: false,
+            remove_spikes: false,
+            collinear_tolerance: T::zero(),
+            sort_interiors: false,
+            sort_polygons: false,
+            drop_empty: false,
+            drop_degenerate_interiors: false,
+            dedupe_interiors: false,
+            drop_exterior_copies: false,
+            round_decimals: None,
+            reject_zero_area: false,
+            coordinate_range: None,
+            max_vertices: None,
+            y_axis: YAxis::Up,
+        }
+    }
+}
+
+impl<T: CoordNum> NormalizeOptions<T> {
+    /// Start building options from the defaults
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_normalized2::{NormalizeOptions, Orientation};
+    ///
+    /// let opts = NormalizeOptions::<f64>::builder()
+    ///     .orientation(Orientation::GeoJson)
+    ///     .dedupe_consecutive(true)
+    ///     .build();
+    /// assert_eq!(opts.orientation, Orientation::GeoJson);
+    /// assert!(opts.dedupe_consecutive);
+    /// assert!(!opts.sort_interiors);
+    /// ```
+    pub fn builder() -> NormalizeOptionsBuilder<T> {
+        NormalizeOptionsBuilder::default()
+    }
+}
+
+/// Chainable builder for [`NormalizeOptions`], created by [`NormalizeOptions::builder`]
+///
+/// Every setter matches the option field of the same name, unset options keep their default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[must_use = "call `build` to get the options"]
+pub struct NormalizeOptionsBuilder<T: CoordNum> {
+    opts: NormalizeOptions<T>,
+}
+
+impl<T: CoordNum> Default for NormalizeOptionsBuilder<T> {
+    fn default() -> Self {
+        NormalizeOptionsBuilder {
+            opts: NormalizeOptions::default(),
+        }
+    }
+}
+
+impl<T: CoordNum> NormalizeOptionsBuilder<T> {
+    /// Set [`NormalizeOptions::orientation`]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.opts.orientation = orientation;
+        self
+    }
+
+    /// Set [`NormalizeOptions::canonical_start`]
+    pub fn canonical_start(mut self, canonical_start: bool) -> Self {
+        self.opts.canonical_start = canonical_start;
+        self
+    }
+
+    /// Set [`NormalizeOptions::dedupe_consecutive`]
+    pub fn dedupe_consecutive(mut self, dedupe_consecutive: bool) -> Self {
+        self.opts.dedupe_consecutive = dedupe_consecutive;
+        self
+    }
+
+    /// Set [`NormalizeOptions::remove_collinear`]
+    pub fn remove_collinear(mut self, remove_collinear: bool) -> Self {
+        self.opts.remove_collinear = remove_collinear;
+        self
+    }
+
+    /// Set [`NormalizeOptions::remove_spikes`]
+    pub fn remove_spikes(mut self, remove_spikes: bool) -> Self {
+        self.opts.remove_spikes = remove_spikes;
+        self
+    }
+
+    /// Set [`NormalizeOptions::collinear_tolerance`]
+    pub fn collinear_tolerance(mut self, collinear_tolerance: T) -> Self {
+        self.opts.collinear_tolerance = collinear_tolerance;
+        self
+    }
+
+    /// Set [`NormalizeOptions::sort_interiors`]
+    pub fn sort_interiors(mut self, sort_interiors: bool) -> Self {
+        self.opts.sort_interiors = sort_interiors;
+        self
+    }
+
+    /// Set [`NormalizeOptions::sort_polygons`]
+    pub fn sort_polygons(mut self, sort_polygons: bool) -> Self {
+        self.opts.sort_polygons = sort_polygons;
+        self
+    }
+
+    /// Set [`NormalizeOptions::drop_empty`]
+    pub fn drop_empty(mut self, drop_empty: bool) -> Self {
+        self.opts.drop_empty = drop_empty;
+        self
+    }
+
+    /// Set [`NormalizeOptions::drop_degenerate_interiors`]
+    pub fn drop_degenerate_interiors(mut self, drop_degenerate_interiors: bool) -> Self {
+        self.opts.drop_degenerate_interiors = drop_degenerate_interiors;
+        self
+    }
+
+    /// Set [`NormalizeOptions::dedupe_interiors`]
+    pub fn dedupe_interiors(mut self, dedupe_interiors: bool) -> Self {
+        self.opts.dedupe_interiors = dedupe_interiors;
+        self
+    }
+
+    /// Set [`NormalizeOptions::drop_exterior_copies`]
+    pub fn drop_exterior_copies(mut self, drop_exterior_copies: bool) -> Self {
+        self.opts.drop_exterior_copies = drop_exterior_copies;
+        self
+    }
+
+    /// Set [`NormalizeOptions::round_decimals`]
+    pub fn round_decimals(mut self, round_decimals: Option<u32>) -> Self {
+        self.opts.round_decimals = round_decimals;
+        self
+    }
+
+    /// Set [`NormalizeOptions::reject_zero_area`]
+    pub fn reject_zero_area(mut self, reject_zero_area: bool) -> Self {
+        self.opts.reject_zero_area = reject_zero_area;
+        self
+    }
+
+    /// Set [`NormalizeOptions::coordinate_range`]
+    pub fn coordinate_range(mut self, coordinate_range: Option<(Coord<T>, Coord<T>)>) -> Self {
+        self.opts.coordinate_range = coordinate_range;
+        self
+    }
+
+    /// Set [`NormalizeOptions::max_vertices`]
+    pub fn max_vertices(mut self, max_vertices: Option<usize>) -> Self {
+        self.opts.max_vertices = max_vertices;
+        self
+    }
+
+    /// Set [`NormalizeOptions::y_axis`]
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.opts.y_axis = y_axis;
+        self
+    }
+
+    /// Finish building the options
+    pub fn build(self) -> NormalizeOptions<T> {
+        self.opts
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_deserialize_options() {
+        use serde::de::value::{Error, MapDeserializer};
+        use serde::de::IntoDeserializer;
+        use serde::Deserialize;
+
+        let orientation = Orientation::deserialize("geojson".into_deserializer());
+        assert_eq!(orientation, Ok::<_, Error>(Orientation::GeoJson));
+
+        let empty = MapDeserializer::<_, Error>::new(std::iter::empty::<(&str, bool)>());
+        let opts = NormalizeOptions::<f64>::deserialize(empty).unwrap();
+        assert_eq!(opts, NormalizeOptions::default());
+
+        let fields = MapDeserializer::<_, Error>::new(vec![("canonical_start", true)].into_iter());
+        let opts = NormalizeOptions::<f64>::deserialize(fields).unwrap();
+        assert!(opts.canonical_start);
+        assert_eq!(opts.orientation, Orientation::Ogc);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{normalize_members, NormalizeOptions, Normalized};
+use core::convert::Infallible;
+use geo_types::{CoordNum, Geometry, GeometryCollection, MultiPolygon};
+use rayon::prelude::*;
+
+/// Parallel normalization of the members of a collection, using rayon
+///
+/// The members are normalized concurrently but the output keeps the input order, so
+/// `normalized_par` always returns the same value as [`Normalized::normalized`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon};
+/// use geo_normalized2::{Normalized, NormalizedPar};
+/// let mp = MultiPolygon(vec![polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ]]);
+///
+/// assert_eq!(mp.normalized_par(), mp.normalized());
+/// ```
+pub trait NormalizedPar<T: CoordNum>: Normalized<T> {
+    /// Return a new normalized geometry, normalizing the members in parallel
+    fn normalized_par(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.normalized_par_with(NormalizeOptions::default())
+    }
+
+    /// Parallel version of [`Normalized::normalized_with`]
+    fn normalized_par_with(&self, opts: NormalizeOptions<T>) -> Self
+    where
+        Self: Sized;
+}
+
+impl<T> NormalizedPar<T> for MultiPolygon<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn normalized_par_with(&self, opts: NormalizeOptions<T>) -> Self {
+        let mut polys = self.0.clone();
+        normalize_members(&mut polys, opts, |polys| {
+            polys
+                .par_iter_mut()
+                .for_each(|poly| poly.normalize_mut_with(opts));
+            Ok::<(), Infallible>(())
+        })
+        .unwrap_or_else(|never| match never {});
+        MultiPolygon(polys)
+    }
+}
+
+impl<T> NormalizedPar<T> for GeometryCollection<T>
+where
+    T: CoordNum + Send + Sync,
+{
+    fn normalized_par_with(&self, opts: NormalizeOptions<T>) -> Self {
+        GeometryCollection(
+            self.0
+                .par_iter()
+                .map(|geom| geom.normalized_with(opts))
+                .collect::<Vec<Geometry<T>>>(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{get_bad_outer_bad_inner_poly, get_bad_outer_poly};
+
+    #[test]
+    fn parallel_matches_serial_normalization() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let mp = MultiPolygon(vec![bad.clone(), good.clone(), bad.clone()]);
+        assert_eq!(mp.normalized_par(), mp.normalized());
+
+        let gc = GeometryCollection(vec![
+            Geometry::Polygon(bad.clone()),
+            Geometry::Point(geo::point!(x: 1.0, y: 1.0)),
+            Geometry::MultiPolygon(mp),
+        ]);
+        assert_eq!(gc.normalized_par(), gc.normalized());
+    }
+
+    #[test]
+    fn parallel_normalization_keeps_input_order() {
+        use geo::MapCoords;
+
+        let (_, bad) = get_bad_outer_bad_inner_poly();
+        let (_, square) = get_bad_outer_poly();
+        let mut polys = (0..2000)
+            .map(|i| {
+                let offset = geo::coord! { x: f64::from(i) * 100., y: 0. };
+                let poly = if i % 2 == 0 { &bad } else { &square };
+                poly.map_coords(|c| c + offset)
+            })
+            .collect::<Vec<_>>();
+        // Deterministic Fisher-Yates shuffle driven by a small linear congruential generator
+        let mut state = 0x2545_f491_u64;
+        for i in (1..polys.len()).rev() {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            polys.swap(i, (state >> 33) as usize % (i + 1));
+        }
+        let mp = MultiPolygon(polys.clone());
+
+        let serial = mp.normalized().0;
+        let parallel = mp.normalized_par().0;
+        assert_eq!(parallel.len(), polys.len());
+        for ((par, ser), input) in parallel.iter().zip(&serial).zip(&polys) {
+            assert_eq!(par, ser);
+            assert_eq!(*par, input.normalized());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::Normalized;
+use core::ops::Deref;
+use geo_types::{CoordNum, Polygon};
+
+/// A polygon that is guaranteed to follow the OGC winding rules
+///
+/// The only way to build one is to normalize a polygon with [`From`], so functions that need
+/// normalized input can take a `NormalizedPolygon` instead of checking, or normalizing, their
+/// argument again. It dereferences to the [`Polygon`] for read access, and
+/// [`into_inner`](NormalizedPolygon::into_inner) gives the polygon back.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{Normalized, NormalizedPolygon};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// let norm = NormalizedPolygon::from(poly.clone());
+/// assert!(norm.is_normalized());
+/// assert_eq!(norm.exterior().0.len(), 5);
+/// assert_eq!(norm.into_inner(), poly.normalized());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedPolygon<T: CoordNum>(Polygon<T>);
+
+impl<T: CoordNum> NormalizedPolygon<T> {
+    /// Unwrap the normalized polygon
+    pub fn into_inner(self) -> Polygon<T> {
+        self.0
+    }
+}
+
+impl<T: CoordNum> From<Polygon<T>> for NormalizedPolygon<T> {
+    /// Normalize the polygon with the OGC winding rules, reusing its allocations
+    fn from(poly: Polygon<T>) -> Self {
+        NormalizedPolygon(poly.into_normalized())
+    }
+}
+
+impl<T: CoordNum> From<NormalizedPolygon<T>> for Polygon<T> {
+    fn from(norm: NormalizedPolygon<T>) -> Self {
+        norm.0
+    }
+}
+
+impl<T: CoordNum> Deref for NormalizedPolygon<T> {
+    type Target = Polygon<T>;
+
+    fn deref(&self) -> &Polygon<T> {
+        &self.0
+    }
+}
+
+impl<T: CoordNum> AsRef<Polygon<T>> for NormalizedPolygon<T> {
+    fn as_ref(&self) -> &Polygon<T> {
+        &self.0
+    }
+}
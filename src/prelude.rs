@@ -0,0 +1,32 @@
+//! Re-exports of the traits and types needed for normalization, so that a single
+//! `use geo_normalized2::prelude::*;` brings the whole API into scope.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::polygon;
+//! use geo_normalized2::prelude::*;
+//! let poly = polygon![
+//!         (x: 1.0, y: 1.0),
+//!         (x: 4.0, y: 1.0),
+//!         (x: 4.0, y: 4.0),
+//!         (x: 1.0, y: 4.0),
+//!         (x: 1.0, y: 1.0),
+//!         ];
+//!
+//! let opts = NormalizeOptions {
+//!     orientation: Orientation::GeoJson,
+//!     ..Default::default()
+//! };
+//! assert!(poly.normalized().is_normalized());
+//! assert_eq!(poly.normalized_with(opts), poly);
+//! ```
+
+pub use crate::{
+    IntoNormalizedMulti, MatchesOrientation, NormalizeDyn, NormalizeError, NormalizeOptions,
+    Normalized, NormalizedIteratorExt, NormalizedPolygon, Orientation, ReverseRings, RingRole,
+    YAxis,
+};
+
+#[cfg(feature = "rayon")]
+pub use crate::NormalizedPar;
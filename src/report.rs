@@ -0,0 +1,170 @@
+#[cfg(feature = "log")]
+use crate::RingRole;
+use crate::{normalize_polygon_mut, NormalizeOptions};
+use alloc::vec::Vec;
+use geo_types::{CoordNum, Polygon};
+
+/// What normalizing a polygon changed, returned by [`normalize_report`]
+///
+/// Point counts are only non-zero when the matching option of [`NormalizeOptions`] is enabled,
+/// except for `closed_rings`, since rings are always closed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NormalizeReport {
+    /// Whether the exterior ring was reversed
+    pub exterior_reversed: bool,
+    /// The indices, in the input polygon, of the interior rings that were reversed
+    pub reversed_interiors: Vec<usize>,
+    /// The number of rings that were closed by repeating their first coordinate
+    pub closed_rings: usize,
+    /// The number of consecutive duplicate coordinates removed by `dedupe_consecutive`
+    pub deduped_points: usize,
+    /// The number of spike coordinates removed by `remove_spikes`
+    pub spike_points: usize,
+    /// The number of collinear vertices removed by `remove_collinear`
+    pub collinear_points: usize,
+    /// The number of zero-area interior rings removed by `drop_degenerate_interiors`
+    pub dropped_interiors: usize,
+    /// The number of duplicate interior rings removed by `dedupe_interiors`
+    pub deduped_interiors: usize,
+    /// The number of interior rings equal to the exterior removed by `drop_exterior_copies`
+    pub dropped_exterior_copies: usize,
+}
+
+impl NormalizeReport {
+    /// Whether normalization changed anything at all
+    pub fn is_unchanged(&self) -> bool {
+        *self == NormalizeReport::default()
+    }
+
+    pub(crate) fn add(&mut self, changes: RingChanges) {
+        self.closed_rings += usize::from(changes.closed);
+        self.deduped_points += changes.deduped_points;
+        self.spike_points += changes.spike_points;
+        self.collinear_points += changes.collinear_points;
+    }
+}
+
+/// What normalizing a single ring changed
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct RingChanges {
+    pub(crate) reversed: bool,
+    pub(crate) closed: bool,
+    pub(crate) deduped_points: usize,
+    pub(crate) spike_points: usize,
+    pub(crate) collinear_points: usize,
+}
+
+impl RingChanges {
+    /// Log the reversal and removed duplicate points of a ring at `debug` level. Each event has
+    /// a constant message, the role, index and count are passed as key-values.
+    #[cfg(feature = "log")]
+    pub(crate) fn log(&self, role: RingRole, index: usize) {
+        if self.reversed {
+            log::debug!(role:? = role, index = index; "reversed ring");
+        }
+        if self.deduped_points > 0 {
+            log::debug!(
+                role:? = role, index = index, count = self.deduped_points;
+                "removed duplicate points"
+            );
+        }
+    }
+}
+
+/// Normalize a polygon with the OGC winding rules and report which rings were changed
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{normalize_report, Normalized};
+/// let poly = polygon!(
+///     exterior: [
+///         (x: 0.0, y: 0.0),
+///         (x: 0.0, y: 9.0),
+///         (x: 9.0, y: 9.0),
+///         (x: 9.0, y: 0.0),
+///         (x: 0.0, y: 0.0),
+///     ],
+///     interiors: [
+///         [
+///             (x: 1.0, y: 1.0),
+///             (x: 1.0, y: 2.0),
+///             (x: 2.0, y: 2.0),
+///             (x: 2.0, y: 1.0),
+///             (x: 1.0, y: 1.0),
+///         ],
+///     ],
+/// );
+///
+/// let (norm, report) = normalize_report(&poly);
+/// assert_eq!(norm, poly.normalized());
+/// assert!(!report.exterior_reversed);
+/// assert_eq!(report.reversed_interiors, vec![0]);
+/// ```
+pub fn normalize_report<T: CoordNum>(poly: &Polygon<T>) -> (Polygon<T>, NormalizeReport) {
+    normalize_report_with(poly, NormalizeOptions::default())
+}
+
+/// Same as [`normalize_report`], normalizing with the settings given in `opts`
+pub fn normalize_report_with<T: CoordNum>(
+    poly: &Polygon<T>,
+    opts: NormalizeOptions<T>,
+) -> (Polygon<T>, NormalizeReport) {
+    let mut norm = poly.clone();
+    let mut report = NormalizeReport::default();
+    normalize_polygon_mut(&mut norm, opts, Some(&mut report), None);
+    (norm, report)
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use super::*;
+    use crate::tests::get_good_outer_bad_inner_poly;
+    use crate::{NormalizeOptions, Normalized};
+
+    #[test]
+    fn logs_reversed_rings_and_removed_points() {
+        use std::sync::Mutex;
+
+        struct Capture(Mutex<Vec<String>>);
+        impl log::Log for Capture {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.target().starts_with("geo_normalized2")
+            }
+            fn log(&self, record: &log::Record) {
+                struct Fields(String);
+                impl<'kvs> log::kv::VisitSource<'kvs> for Fields {
+                    fn visit_pair(
+                        &mut self,
+                        key: log::kv::Key<'kvs>,
+                        value: log::kv::Value<'kvs>,
+                    ) -> Result<(), log::kv::Error> {
+                        self.0 += &format!(" {}={}", key, value);
+                        Ok(())
+                    }
+                }
+                if self.enabled(record.metadata()) {
+                    let mut fields = Fields(record.args().to_string());
+                    record.key_values().visit(&mut fields).unwrap();
+                    self.0.lock().unwrap().push(fields.0);
+                }
+            }
+            fn flush(&self) {}
+        }
+        static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let (_, bad) = get_good_outer_bad_inner_poly();
+        let mut doubled = bad.clone();
+        doubled.exterior_mut(|ring| ring.0.insert(1, ring.0[1]));
+        let opts = NormalizeOptions::builder().dedupe_consecutive(true).build();
+        doubled.normalize_mut_with(opts);
+        let logged = CAPTURE.0.lock().unwrap();
+        assert!(logged.contains(&"reversed ring role=Interior index=0".to_string()));
+        assert!(
+            logged.contains(&"removed duplicate points role=Exterior index=0 count=1".to_string())
+        );
+    }
+}
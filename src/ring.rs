@@ -0,0 +1,321 @@
+use crate::{ApproxEqNormalized, WindingOrder};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use geo_types::{Coord, CoordNum, LineString, Polygon};
+use num_traits::float::FloatCore;
+
+/// Compare two coordinates lexicographically, by `x` and then by `y`
+///
+/// `NaN` values sort after all numbers, so this is a total order and sorting with it never
+/// panics.
+pub(crate) fn cmp_coords<T: CoordNum>(a: &Coord<T>, b: &Coord<T>) -> Ordering {
+    cmp_values(a.x, b.x).then_with(|| cmp_values(a.y, b.y))
+}
+
+/// Compare two values, with `NaN`, the only value that is not equal to itself, after all others
+#[allow(clippy::eq_op)]
+fn cmp_values<T: CoordNum>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
+
+/// The lexicographically smallest coordinate of a ring, see [`cmp_coords`]
+pub(crate) fn min_coord<T: CoordNum>(ring: &LineString<T>) -> Option<&Coord<T>> {
+    ring.0.iter().min_by(|a, b| cmp_coords(a, b))
+}
+
+/// Order rings by their smallest coordinate, then by their number of coordinates. Empty rings
+/// come first.
+pub(crate) fn cmp_rings<T: CoordNum>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+    match (min_coord(a), min_coord(b)) {
+        (Some(ma), Some(mb)) => cmp_coords(ma, mb),
+        (ma, mb) => ma.is_some().cmp(&mb.is_some()),
+    }
+    .then_with(|| a.0.len().cmp(&b.0.len()))
+}
+
+/// Order polygons by their exterior ring, see [`cmp_rings`]
+pub(crate) fn cmp_polygons<T: CoordNum>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering {
+    cmp_rings(a.exterior(), b.exterior())
+}
+
+/// The index a closed ring would start at after [`rotate_to_min_start`], or `0` for rings that
+/// are not closed
+pub(crate) fn min_start<T: CoordNum>(ring: &LineString<T>) -> usize {
+    if ring.0.len() < 2 || !ring.is_closed() {
+        return 0;
+    }
+    (0..ring.0.len() - 1)
+        .min_by(|&a, &b| cmp_coords(&ring.0[a], &ring.0[b]))
+        .unwrap_or(0)
+}
+
+/// Rotate a closed ring so that it starts, and ends, at its lexicographically smallest coordinate
+///
+/// Rings that are not closed are left untouched, since rotating them would change their shape.
+pub(crate) fn rotate_to_min_start<T: CoordNum>(ring: &mut LineString<T>) {
+    let start = min_start(ring);
+    if start == 0 {
+        return;
+    }
+    ring.0.pop();
+    ring.0.rotate_left(start);
+    ring.0.push(ring.0[0]);
+}
+
+/// Whether two rings have the same coordinates, regardless of the vertex closed rings start at
+pub(crate) fn is_same_ring<T: CoordNum>(a: &LineString<T>, b: &LineString<T>) -> bool {
+    a.approx_eq(b, T::zero())
+}
+
+/// Whether two rings trace the same boundary, in either direction, see [`is_same_ring`]
+pub(crate) fn is_same_boundary<T: CoordNum>(a: &LineString<T>, b: &LineString<T>) -> bool {
+    if a.0.len() != b.0.len() {
+        return false;
+    }
+    let mut reversed = b.clone();
+    reversed.0.reverse();
+    is_same_ring(a, b) || is_same_ring(a, &reversed)
+}
+
+/// Remove rings that are the same as an earlier ring, see [`is_same_ring`]
+pub(crate) fn dedupe_rings<T: CoordNum>(rings: &mut Vec<LineString<T>>) {
+    let mut i = 1;
+    while i < rings.len() {
+        if rings[..i].iter().any(|ring| is_same_ring(ring, &rings[i])) {
+            rings.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Round every coordinate of a ring to `decimals` decimal places
+///
+/// Integer coordinates are already rounded and are left untouched, as are values that cannot be
+/// scaled by `10^decimals` without overflowing.
+pub(crate) fn round_coords<T: CoordNum>(ring: &mut LineString<T>, decimals: u32) {
+    if T::from(0.5) == Some(T::zero()) {
+        return;
+    }
+    let factor = FloatCore::powi(10.0_f64, decimals.min(i32::MAX as u32) as i32);
+    let round = |value: T| {
+        value
+            .to_f64()
+            .map(|v| v * factor)
+            .filter(|scaled| scaled.is_finite())
+            .and_then(|scaled| T::from(FloatCore::round(scaled) / factor))
+            .unwrap_or(value)
+    };
+    for c in ring.0.iter_mut() {
+        c.x = round(c.x);
+        c.y = round(c.y);
+    }
+}
+
+/// Twice the signed area enclosed by a ring, by the shoelace formula, see
+/// [`crate::ring_signed_area`]
+pub(crate) fn twice_signed_area<T: CoordNum>(ring: &LineString<T>) -> T {
+    let coords = &ring.0;
+    let Some(first) = coords.first() else {
+        return T::zero();
+    };
+    coords
+        .iter()
+        .zip(coords.iter().skip(1).chain(core::iter::once(first)))
+        .fold(T::zero(), |sum, (a, b)| sum + a.x * b.y - b.x * a.y)
+}
+
+/// The absolute value of a number, for coordinate types that are not `Signed`
+pub(crate) fn abs<T: CoordNum>(value: T) -> T {
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+/// Whether the signed area enclosed by a ring is exactly zero, as for rings whose points are all
+/// collinear
+pub(crate) fn has_zero_area<T: CoordNum>(ring: &LineString<T>) -> bool {
+    twice_signed_area(ring) == T::zero()
+}
+
+/// The winding order of a ring, found as geo's `Winding::winding_order` does: from the turn the
+/// ring makes at its lexicographically smallest coordinate
+///
+/// Returns `None` when the ring is not closed, has fewer than three distinct coordinates or
+/// does not turn at that coordinate.
+pub(crate) fn winding_order<T: CoordNum>(ring: &LineString<T>) -> Option<WindingOrder> {
+    let coords = &ring.0;
+    let len = coords.len();
+    if len < 4 || !ring.is_closed() {
+        return None;
+    }
+    let (i, least) = coords
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| cmp_coords(a, b))?;
+    let next = (1..len)
+        .map(|k| &coords[(i + k) % len])
+        .find(|c| *c != least)?;
+    let prev = (1..len)
+        .map(|k| &coords[(i + len - k) % len])
+        .find(|c| *c != least)?;
+    orientation(*prev, *least, *next)
+}
+
+/// The way the path from `a` through `b` to `c` turns, or `None` when the three are collinear
+pub(crate) fn orientation<T: CoordNum>(
+    a: Coord<T>,
+    b: Coord<T>,
+    c: Coord<T>,
+) -> Option<WindingOrder> {
+    let cross = (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x);
+    if cross > T::zero() {
+        Some(WindingOrder::CounterClockwise)
+    } else if cross < T::zero() {
+        Some(WindingOrder::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// Reverse a ring if it is wound against `wanted`, returning whether it was reversed
+///
+/// Rings without a defined winding are left as they are. That includes rings with a signed area
+/// of exactly zero, such as a figure of eight with equal lobes, which geo may still give a
+/// winding to.
+pub(crate) fn wind<T: CoordNum>(ring: &mut LineString<T>, wanted: WindingOrder) -> bool {
+    if needs_reversal(winding_order(ring), wanted, || twice_signed_area(ring)) {
+        ring.0.reverse();
+        true
+    } else {
+        false
+    }
+}
+
+/// Same as [`wind`], for a ring whose [`twice_signed_area`] is already known
+pub(crate) fn wind_with_area<T: CoordNum>(
+    ring: &mut LineString<T>,
+    wanted: WindingOrder,
+    twice_area: T,
+) -> bool {
+    if needs_reversal(winding_order(ring), wanted, || twice_area) {
+        ring.0.reverse();
+        true
+    } else {
+        false
+    }
+}
+
+/// Whether [`wind`] reverses a ring that has the given `winding` to follow `wanted`. The
+/// `twice_area` of the ring is only needed, and so only called, when the windings differ.
+pub(crate) fn needs_reversal<T: CoordNum>(
+    winding: Option<WindingOrder>,
+    wanted: WindingOrder,
+    twice_area: impl FnOnce() -> T,
+) -> bool {
+    winding.is_some_and(|winding| winding != wanted) && twice_area() != T::zero()
+}
+
+/// Whether a ring already has the winding [`wind`] would give it for `wanted`: it is wound that
+/// way, it has no winding to fix, or no winding is wanted
+pub(crate) fn is_wound<T: CoordNum>(ring: &LineString<T>, wanted: Option<WindingOrder>) -> bool {
+    wanted.is_none_or(|wanted| {
+        !needs_reversal(winding_order(ring), wanted, || twice_signed_area(ring))
+    })
+}
+
+/// Whether `b` lies on the straight line from `a` to `c`, within `tolerance`, with the line
+/// continuing in the same direction through `b`
+fn is_collinear<T: CoordNum>(a: Coord<T>, b: Coord<T>, c: Coord<T>, tolerance: T) -> bool {
+    let (ab, bc) = (b - a, c - b);
+    let cross = ab.x * bc.y - ab.y * bc.x;
+    let dot = ab.x * bc.x + ab.y * bc.y;
+    cross <= tolerance && T::zero() - cross <= tolerance && dot > T::zero()
+}
+
+/// Remove the vertices of a closed ring that lie on a straight line between their neighbours
+pub(crate) fn remove_collinear<T: CoordNum>(ring: &mut LineString<T>, tolerance: T) {
+    if ring.0.len() < 4 || !ring.is_closed() {
+        return;
+    }
+    ring.0.pop();
+    let mut len = 0;
+    for i in 0..ring.0.len() {
+        let c = ring.0[i];
+        while len >= 2 && is_collinear(ring.0[len - 2], ring.0[len - 1], c, tolerance) {
+            len -= 1;
+        }
+        ring.0[len] = c;
+        len += 1;
+    }
+    ring.0.truncate(len);
+    // The ring wraps around, so the vertices on either side of the start need checking too
+    while ring.0.len() >= 3 {
+        let n = ring.0.len();
+        if is_collinear(ring.0[n - 2], ring.0[n - 1], ring.0[0], tolerance) {
+            ring.0.pop();
+        } else if is_collinear(ring.0[n - 1], ring.0[0], ring.0[1], tolerance) {
+            ring.0.remove(0);
+        } else {
+            break;
+        }
+    }
+    ring.0.push(ring.0[0]);
+}
+
+/// Whether `b` is the tip of a spike: the ring goes from `a` to `b` and straight back towards
+/// `a`, within `tolerance`
+fn is_spike<T: CoordNum>(a: Coord<T>, b: Coord<T>, c: Coord<T>, tolerance: T) -> bool {
+    let (ab, bc) = (b - a, c - b);
+    let cross = ab.x * bc.y - ab.y * bc.x;
+    let dot = ab.x * bc.x + ab.y * bc.y;
+    cross <= tolerance && T::zero() - cross <= tolerance && dot < T::zero()
+}
+
+/// Remove the tips of spikes from a closed ring, along with the duplicate coordinate left
+/// where a spike returns exactly to where it started. Chains of spikes are removed one tip at a
+/// time, so an antenna of several segments is removed completely. Other repeated coordinates
+/// are kept.
+pub(crate) fn remove_spikes<T: CoordNum>(ring: &mut LineString<T>, tolerance: T) {
+    if ring.0.len() < 4 || !ring.is_closed() {
+        return;
+    }
+    ring.0.pop();
+    let mut len = 0;
+    // Whether a tip was removed since the last coordinate was kept, leaving a duplicate to drop
+    let mut removed_tip = false;
+    for i in 0..ring.0.len() {
+        let c = ring.0[i];
+        while len >= 2 && is_spike(ring.0[len - 2], ring.0[len - 1], c, tolerance) {
+            len -= 1;
+            removed_tip = true;
+        }
+        if removed_tip && ring.0[len - 1] == c {
+            removed_tip = false;
+            continue;
+        }
+        removed_tip = false;
+        ring.0[len] = c;
+        len += 1;
+    }
+    ring.0.truncate(len);
+    // The ring wraps around, so spikes on either side of the start need checking too
+    while ring.0.len() >= 3 {
+        let n = ring.0.len();
+        if removed_tip && ring.0[n - 1] == ring.0[0] {
+            ring.0.pop();
+            removed_tip = false;
+        } else if is_spike(ring.0[n - 2], ring.0[n - 1], ring.0[0], tolerance) {
+            ring.0.pop();
+            removed_tip = true;
+        } else if is_spike(ring.0[n - 1], ring.0[0], ring.0[1], tolerance) {
+            ring.0.remove(0);
+            removed_tip = true;
+        } else {
+            break;
+        }
+    }
+    ring.0.push(ring.0[0]);
+}
@@ -0,0 +1,110 @@
+use crate::Normalized;
+use alloc::string::String;
+use core::fmt::{Display, Write};
+use geo_types::{CoordNum, LineString, MultiPolygon, Polygon};
+
+/// Render a geometry as the `d` attribute of an SVG `<path>`, for debugging
+pub trait ToSvgPath {
+    /// The SVG path data of the normalized geometry
+    ///
+    /// The geometry is normalized with the OGC winding rules first, so every hole winds
+    /// opposite to its exterior and is cut out under both the `nonzero` and the `evenodd`
+    /// `fill-rule`. Each ring becomes one subpath, `M` to its first coordinate, `L` to the
+    /// others and `Z` to close it, with the coordinates written as they are. SVG's `y` axis
+    /// points down, so flip the `y` axis with a transform to draw the geometry the right way up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::ToSvgPath;
+    /// let poly = polygon!(
+    ///     exterior: [
+    ///         (x: 0, y: 0),
+    ///         (x: 9, y: 0),
+    ///         (x: 9, y: 9),
+    ///         (x: 0, y: 9),
+    ///         (x: 0, y: 0),
+    ///     ],
+    ///     interiors: [
+    ///         [
+    ///             (x: 1, y: 1),
+    ///             (x: 1, y: 2),
+    ///             (x: 2, y: 2),
+    ///             (x: 1, y: 1),
+    ///         ],
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     poly.to_svg_path(),
+    ///     "M0 0L0 9L9 9L9 0Z M1 1L2 2L1 2Z"
+    /// );
+    /// ```
+    fn to_svg_path(&self) -> String;
+}
+
+impl<T: CoordNum + Display> ToSvgPath for Polygon<T> {
+    fn to_svg_path(&self) -> String {
+        let mut out = String::new();
+        write_polygon(&mut out, &self.normalized());
+        out
+    }
+}
+
+impl<T: CoordNum + Display> ToSvgPath for MultiPolygon<T> {
+    fn to_svg_path(&self) -> String {
+        let mut out = String::new();
+        for poly in self.normalized().0.iter() {
+            write_polygon(&mut out, poly);
+        }
+        out
+    }
+}
+
+fn write_polygon<T: CoordNum + Display>(out: &mut String, poly: &Polygon<T>) {
+    for ring in core::iter::once(poly.exterior()).chain(poly.interiors()) {
+        write_ring(out, ring);
+    }
+}
+
+fn write_ring<T: CoordNum + Display>(out: &mut String, ring: &LineString<T>) {
+    // The closing coordinate repeats the first one, `Z` draws that edge instead
+    let open = ring.0.len().saturating_sub(usize::from(ring.is_closed()));
+    if open == 0 {
+        return;
+    }
+    if !out.is_empty() {
+        out.push(' ');
+    }
+    for (i, c) in ring.0[..open].iter().enumerate() {
+        let command = if i == 0 { 'M' } else { 'L' };
+        let _ = write!(out, "{}{} {}", command, c.x, c.y);
+    }
+    out.push('Z');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::get_bad_outer_bad_inner_poly;
+
+    #[test]
+    fn svg_paths_wind_holes_against_their_exterior() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        let path = "M0 0L0 50L50 50L50 0Z M10 10L20 10L20 20L10 20Z";
+        assert_eq!(bad.to_svg_path(), path);
+        assert_eq!(good.to_svg_path(), path);
+        assert_eq!(
+            MultiPolygon(vec![bad.clone(), bad]).to_svg_path(),
+            format!("{} {}", path, path)
+        );
+        assert_eq!(MultiPolygon::<f64>(vec![]).to_svg_path(), "");
+
+        let fractional = Polygon::new(
+            LineString::from(vec![(0.5, -1.25), (0., 2.), (3., 0.)]),
+            vec![],
+        );
+        assert_eq!(fractional.to_svg_path(), "M0.5 -1.25L0 2L3 0Z");
+    }
+}
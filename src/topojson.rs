@@ -0,0 +1,296 @@
+use crate::{ring, Orientation};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use geo_types::{Coord, LineString};
+use serde_json::Value;
+
+/// An error from reading a TopoJSON topology
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid TopoJSON: {message}")]
+pub struct TopoJsonError {
+    /// What was wrong with the input
+    pub message: String,
+}
+
+fn error(message: impl ToString) -> TopoJsonError {
+    TopoJsonError {
+        message: message.to_string(),
+    }
+}
+
+/// Normalize the polygon winding of a TopoJSON topology with the OGC winding rules, clockwise
+/// exterior rings and counter-clockwise interior rings, as produced by the reference TopoJSON
+/// tools
+///
+/// Rings are fixed by reversing their list of arc references and flipping the sign of each
+/// reference, so the shared arcs themselves are never changed or duplicated. `Polygon` and
+/// `MultiPolygon` objects are normalized, also within a `GeometryCollection`; all other objects,
+/// the arcs, the transform and any other members are passed through untouched. Quantized,
+/// delta encoded arcs are decoded with the topology's `transform` to find the winding.
+///
+/// The topology is read and written with serde_json, keeping the order of object members. The
+/// output is written without insignificant whitespace, and documents nested more than 128 levels
+/// deep are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_topojson;
+///
+/// // A counter-clockwise square, split into two arcs
+/// let topology = r#"{"type": "Topology",
+///     "objects": {"square": {"type": "Polygon", "arcs": [[0, 1]]}},
+///     "arcs": [[[0, 0], [1, 0], [1, 1]], [[1, 1], [0, 1], [0, 0]]]}"#;
+/// assert_eq!(
+///     normalize_topojson(topology).unwrap(),
+///     r#"{"type":"Topology","objects":{"square":{"type":"Polygon","arcs":[[-2,-1]]}},"arcs":[[[0,0],[1,0],[1,1]],[[1,1],[0,1],[0,0]]]}"#
+/// );
+/// ```
+pub fn normalize_topojson(input: &str) -> Result<String, TopoJsonError> {
+    normalize_topojson_with(input, Orientation::Ogc)
+}
+
+/// Same as [`normalize_topojson`], producing the winding of the given `orientation`
+pub fn normalize_topojson_with(
+    input: &str,
+    orientation: Orientation,
+) -> Result<String, TopoJsonError> {
+    let mut doc: Value = serde_json::from_str(input).map_err(error)?;
+    if doc.get("type").and_then(Value::as_str) != Some("Topology") {
+        return Err(error("document is not a Topology"));
+    }
+    let arcs = decode_arcs(&doc)?;
+    match doc.get_mut("objects") {
+        Some(Value::Object(objects)) => {
+            for object in objects.values_mut() {
+                normalize_object(object, &arcs, orientation)?;
+            }
+        }
+        _ => return Err(error("Topology has no \"objects\" object")),
+    }
+    Ok(doc.to_string())
+}
+
+/// The absolute coordinates of every arc, undoing the quantization and delta encoding of the
+/// `transform` when there is one
+fn decode_arcs(doc: &Value) -> Result<Vec<Vec<Coord<f64>>>, TopoJsonError> {
+    let transform = match doc.get("transform") {
+        Some(transform) => Some((
+            pair(transform.get("scale"), "scale")?,
+            pair(transform.get("translate"), "translate")?,
+        )),
+        None => None,
+    };
+    let arcs = match doc.get("arcs") {
+        Some(Value::Array(arcs)) => arcs,
+        _ => return Err(error("Topology has no \"arcs\" array")),
+    };
+    arcs.iter()
+        .map(|arc| {
+            let positions = match arc {
+                Value::Array(positions) => positions,
+                _ => return Err(error("an arc must be an array of positions")),
+            };
+            let mut coords = Vec::with_capacity(positions.len());
+            let mut cursor = Coord { x: 0., y: 0. };
+            for position in positions {
+                let c = pair(Some(position), "position")?;
+                coords.push(match transform {
+                    Some((scale, translate)) => {
+                        cursor = cursor + c;
+                        Coord {
+                            x: cursor.x * scale.x + translate.x,
+                            y: cursor.y * scale.y + translate.y,
+                        }
+                    }
+                    None => c,
+                });
+            }
+            Ok(coords)
+        })
+        .collect()
+}
+
+/// The first two numbers of an array, any further members are ignored
+fn pair(json: Option<&Value>, what: &str) -> Result<Coord<f64>, TopoJsonError> {
+    let number = |value: Option<&Value>| {
+        value
+            .and_then(Value::as_f64)
+            .ok_or_else(|| error(format!("a {} must start with two numbers", what)))
+    };
+    match json {
+        Some(Value::Array(values)) => Ok(Coord {
+            x: number(values.first())?,
+            y: number(values.get(1))?,
+        }),
+        _ => Err(error(format!("a {} must be an array", what))),
+    }
+}
+
+/// Normalize a TopoJSON geometry object of any type
+fn normalize_object(
+    obj: &mut Value,
+    arcs: &[Vec<Coord<f64>>],
+    orientation: Orientation,
+) -> Result<(), TopoJsonError> {
+    let kind = match obj.get("type") {
+        Some(Value::String(kind)) => kind.clone(),
+        _ => return Err(error("object has no \"type\"")),
+    };
+    match kind.as_str() {
+        "GeometryCollection" => match obj.get_mut("geometries") {
+            Some(Value::Array(geometries)) => geometries
+                .iter_mut()
+                .try_for_each(|geometry| normalize_object(geometry, arcs, orientation)),
+            _ => Err(error("GeometryCollection has no \"geometries\" array")),
+        },
+        "Polygon" => normalize_polygon(arc_refs(obj)?, arcs, orientation),
+        "MultiPolygon" => match arc_refs(obj)? {
+            Value::Array(polygons) => polygons
+                .iter_mut()
+                .try_for_each(|polygon| normalize_polygon(polygon, arcs, orientation)),
+            _ => Err(error("MultiPolygon arcs must be an array")),
+        },
+        _ => Ok(()),
+    }
+}
+
+fn arc_refs(obj: &mut Value) -> Result<&mut Value, TopoJsonError> {
+    obj.get_mut("arcs")
+        .ok_or_else(|| error("geometry has no \"arcs\""))
+}
+
+/// Reverse the rings of a Polygon's arc references that are not wound as `orientation` requires
+fn normalize_polygon(
+    polygon: &mut Value,
+    arcs: &[Vec<Coord<f64>>],
+    orientation: Orientation,
+) -> Result<(), TopoJsonError> {
+    let rings = match polygon {
+        Value::Array(rings) => rings,
+        _ => return Err(error("Polygon arcs must be an array of rings")),
+    };
+    let mut exterior = None;
+    for (i, ring) in rings.iter_mut().enumerate() {
+        let refs = match ring {
+            Value::Array(refs) => refs,
+            _ => return Err(error("a ring must be an array of arc indices")),
+        };
+        let indices = refs
+            .iter()
+            .map(arc_index)
+            .collect::<Result<Vec<i64>, TopoJsonError>>()?;
+        let line = ring_coords(&indices, arcs)?;
+        let current = ring::winding_order(&line).filter(|_| !ring::has_zero_area(&line));
+        let wanted = if i == 0 {
+            orientation.exterior_winding()
+        } else {
+            orientation.interior_winding_within(exterior)
+        };
+        if let (Some(current), Some(wanted)) = (current, wanted) {
+            if current != wanted {
+                *refs = indices
+                    .iter()
+                    .rev()
+                    .map(|index| Value::from(!index))
+                    .collect();
+            }
+        }
+        if i == 0 {
+            exterior = current.map(|current| wanted.unwrap_or(current));
+        }
+    }
+    Ok(())
+}
+
+fn arc_index(json: &Value) -> Result<i64, TopoJsonError> {
+    match json {
+        Value::Number(n) => n
+            .as_i64()
+            .ok_or_else(|| error(format!("invalid arc index {}", n))),
+        _ => Err(error("an arc index must be an integer")),
+    }
+}
+
+/// The coordinates of a ring, joining its arcs. A negative index `!i` refers to arc `i`
+/// traversed backwards.
+fn ring_coords(
+    indices: &[i64],
+    arcs: &[Vec<Coord<f64>>],
+) -> Result<LineString<f64>, TopoJsonError> {
+    let mut coords = Vec::new();
+    for &index in indices {
+        let (arc, reversed) = if index < 0 {
+            (!index, true)
+        } else {
+            (index, false)
+        };
+        let arc = usize::try_from(arc)
+            .ok()
+            .and_then(|arc| arcs.get(arc))
+            .ok_or_else(|| error(format!("arc index {} is out of range", index)))?;
+        if reversed {
+            coords.extend(arc.iter().rev());
+        } else {
+            coords.extend(arc.iter());
+        }
+    }
+    let mut line = LineString::new(coords);
+    line.close();
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn topojson_rings_are_fixed_through_arc_references() {
+        // Two squares side by side, quantized and delta encoded. Arc 1 is the edge they share,
+        // arcs 0 and 2 the rest of each square, so "left" is counter-clockwise and "right" is
+        // clockwise.
+        let topology = r#"{"type":"Topology","transform":{"scale":[0.5,0.5],"translate":[10,20]},
+            "objects":{"shapes":{"type":"GeometryCollection","geometries":[
+                {"type":"Polygon","arcs":[[0,1]],"properties":{"name":"left"}},
+                {"type":"MultiPolygon","arcs":[[[2,-2]]]},
+                {"type":"LineString","arcs":[0]}]}},
+            "arcs":[[[2,2],[-2,0],[0,-2],[2,0]],[[2,0],[0,2]],[[2,2],[2,0],[0,-2],[-2,0]]]}"#;
+        let norm = normalize_topojson(topology).unwrap();
+        assert!(
+            norm.contains(r#"{"type":"Polygon","arcs":[[-2,-1]],"properties":{"name":"left"}}"#)
+        );
+        assert!(norm.contains(r#"{"type":"MultiPolygon","arcs":[[[2,-2]]]}"#));
+        assert!(norm.contains(r#"{"type":"LineString","arcs":[0]}"#));
+        assert!(norm.contains(
+            r#""arcs":[[[2,2],[-2,0],[0,-2],[2,0]],[[2,0],[0,2]],[[2,2],[2,0],[0,-2],[-2,0]]]"#
+        ));
+        assert_eq!(normalize_topojson(&norm).unwrap(), norm);
+
+        let geojson = normalize_topojson_with(&norm, Orientation::GeoJson).unwrap();
+        assert!(geojson.contains(r#""arcs":[[0,1]]"#));
+        assert!(geojson.contains(r#""arcs":[[[1,-3]]]"#));
+
+        assert_eq!(
+            normalize_topojson(r#"{"type":"Polygon","arcs":[]}"#)
+                .unwrap_err()
+                .message,
+            "document is not a Topology"
+        );
+        let err = normalize_topojson(
+            r#"{"type":"Topology","objects":{"a":{"type":"Polygon","arcs":[[5]]}},"arcs":[]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid TopoJSON: arc index 5 is out of range"
+        );
+        assert!(matches!(Error::from(err), Error::TopoJson(_)));
+        assert!(normalize_topojson(&"[".repeat(100_000))
+            .unwrap_err()
+            .message
+            .starts_with("recursion limit exceeded"));
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{NormalizeOptions, Normalized};
+use geo_traits::to_geo::{ToGeoMultiPolygon, ToGeoPolygon};
+use geo_traits::{MultiPolygonTrait, PolygonTrait};
+use geo_types::{CoordNum, MultiPolygon, Polygon};
+
+/// Normalize any polygon that implements geo-traits' [`PolygonTrait`] with the OGC winding
+/// rules, returning a geo-types [`Polygon`]
+///
+/// This lets geometries stored in other formats, such as geoarrow arrays, be normalized
+/// without converting them to geo-types first. The rings are read once, into the returned
+/// polygon, which is then normalized in place. A polygon without an exterior ring gives a
+/// polygon with an empty exterior. Z and M values are dropped, as geo-types has no room for
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{normalize_polygon_trait, Normalized};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// // geo-types polygons implement `PolygonTrait` too
+/// assert_eq!(normalize_polygon_trait(&poly), poly.normalized());
+/// ```
+pub fn normalize_polygon_trait<T: CoordNum>(poly: &impl PolygonTrait<T = T>) -> Polygon<T> {
+    normalize_polygon_trait_with(poly, NormalizeOptions::default())
+}
+
+/// Same as [`normalize_polygon_trait`], normalizing with the settings given in `opts`
+pub fn normalize_polygon_trait_with<T: CoordNum>(
+    poly: &impl PolygonTrait<T = T>,
+    opts: NormalizeOptions<T>,
+) -> Polygon<T> {
+    let mut norm = poly.to_polygon();
+    norm.normalize_mut_with(opts);
+    norm
+}
+
+/// Normalize any multipolygon that implements geo-traits' [`MultiPolygonTrait`] with the OGC
+/// winding rules, returning a geo-types [`MultiPolygon`], see [`normalize_polygon_trait`]
+pub fn normalize_multi_polygon_trait<T: CoordNum>(
+    multi: &impl MultiPolygonTrait<T = T>,
+) -> MultiPolygon<T> {
+    normalize_multi_polygon_trait_with(multi, NormalizeOptions::default())
+}
+
+/// Same as [`normalize_multi_polygon_trait`], normalizing with the settings given in `opts`
+pub fn normalize_multi_polygon_trait_with<T: CoordNum>(
+    multi: &impl MultiPolygonTrait<T = T>,
+    opts: NormalizeOptions<T>,
+) -> MultiPolygon<T> {
+    let mut norm = multi.to_multi_polygon();
+    norm.normalize_mut_with(opts);
+    norm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{get_bad_outer_bad_inner_poly, get_bad_outer_poly};
+    use crate::Orientation;
+    use geo_traits::{
+        CoordTrait, Dimensions, GeometryTrait, LineStringTrait, UnimplementedGeometryCollection,
+        UnimplementedLine, UnimplementedLineString, UnimplementedMultiLineString,
+        UnimplementedMultiPoint, UnimplementedMultiPolygon, UnimplementedPoint,
+        UnimplementedPolygon, UnimplementedRect, UnimplementedTriangle,
+    };
+
+    /// A polygon stored as flat coordinate columns, with the offsets at which each ring starts,
+    /// standing in for a foreign columnar format
+    struct Columns {
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        ring_offsets: Vec<usize>,
+    }
+
+    impl Columns {
+        fn new(poly: &Polygon<f64>) -> Self {
+            let mut columns = Columns {
+                xs: Vec::new(),
+                ys: Vec::new(),
+                ring_offsets: vec![0],
+            };
+            for ring in core::iter::once(poly.exterior()).chain(poly.interiors()) {
+                columns.xs.extend(ring.0.iter().map(|c| c.x));
+                columns.ys.extend(ring.0.iter().map(|c| c.y));
+                columns.ring_offsets.push(columns.xs.len());
+            }
+            columns
+        }
+
+        fn ring(&self, i: usize) -> Ring<'_> {
+            Ring {
+                columns: self,
+                start: self.ring_offsets[i],
+                end: self.ring_offsets[i + 1],
+            }
+        }
+    }
+
+    struct Ring<'a> {
+        columns: &'a Columns,
+        start: usize,
+        end: usize,
+    }
+
+    struct Point(f64, f64);
+
+    impl CoordTrait for Point {
+        type T = f64;
+
+        fn dim(&self) -> Dimensions {
+            Dimensions::Xy
+        }
+
+        fn x(&self) -> f64 {
+            self.0
+        }
+
+        fn y(&self) -> f64 {
+            self.1
+        }
+
+        fn nth_or_panic(&self, n: usize) -> f64 {
+            [self.0, self.1][n]
+        }
+    }
+
+    /// Implements the `GeometryTrait` plumbing that a polygon or ring type needs, with every
+    /// other geometry left unimplemented
+    macro_rules! geometry {
+        ($ty:ty, $variant:ident) => {
+            impl GeometryTrait for $ty {
+                type T = f64;
+                type PointType<'b>
+                    = UnimplementedPoint<f64>
+                where
+                    Self: 'b;
+                type LineStringType<'b>
+                    = UnimplementedLineString<f64>
+                where
+                    Self: 'b;
+                type PolygonType<'b>
+                    = UnimplementedPolygon<f64>
+                where
+                    Self: 'b;
+                type MultiPointType<'b>
+                    = UnimplementedMultiPoint<f64>
+                where
+                    Self: 'b;
+                type MultiLineStringType<'b>
+                    = UnimplementedMultiLineString<f64>
+                where
+                    Self: 'b;
+                type MultiPolygonType<'b>
+                    = UnimplementedMultiPolygon<f64>
+                where
+                    Self: 'b;
+                type GeometryCollectionType<'b>
+                    = UnimplementedGeometryCollection<f64>
+                where
+                    Self: 'b;
+                type RectType<'b>
+                    = UnimplementedRect<f64>
+                where
+                    Self: 'b;
+                type TriangleType<'b>
+                    = UnimplementedTriangle<f64>
+                where
+                    Self: 'b;
+                type LineType<'b>
+                    = UnimplementedLine<f64>
+                where
+                    Self: 'b;
+
+                fn dim(&self) -> Dimensions {
+                    Dimensions::Xy
+                }
+
+                fn as_type(
+                    &self,
+                ) -> geo_traits::GeometryType<
+                    '_,
+                    UnimplementedPoint<f64>,
+                    UnimplementedLineString<f64>,
+                    UnimplementedPolygon<f64>,
+                    UnimplementedMultiPoint<f64>,
+                    UnimplementedMultiLineString<f64>,
+                    UnimplementedMultiPolygon<f64>,
+                    UnimplementedGeometryCollection<f64>,
+                    UnimplementedRect<f64>,
+                    UnimplementedTriangle<f64>,
+                    UnimplementedLine<f64>,
+                > {
+                    unimplemented!(concat!("only accessed as a ", stringify!($variant)))
+                }
+            }
+        };
+    }
+
+    geometry!(Columns, polygon);
+    geometry!(Ring<'_>, ring);
+
+    impl LineStringTrait for Ring<'_> {
+        type CoordType<'b>
+            = Point
+        where
+            Self: 'b;
+
+        fn num_coords(&self) -> usize {
+            self.end - self.start
+        }
+
+        unsafe fn coord_unchecked(&self, i: usize) -> Point {
+            let i = self.start + i;
+            Point(self.columns.xs[i], self.columns.ys[i])
+        }
+    }
+
+    impl PolygonTrait for Columns {
+        type RingType<'b>
+            = Ring<'b>
+        where
+            Self: 'b;
+
+        fn exterior(&self) -> Option<Ring<'_>> {
+            Some(self.ring(0))
+        }
+
+        fn num_interiors(&self) -> usize {
+            self.ring_offsets.len() - 2
+        }
+
+        unsafe fn interior_unchecked(&self, i: usize) -> Ring<'_> {
+            self.ring(i + 1)
+        }
+    }
+
+    #[test]
+    fn normalizes_a_foreign_polygon() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(normalize_polygon_trait(&Columns::new(&bad)), good);
+        assert_eq!(normalize_polygon_trait(&Columns::new(&good)), good);
+    }
+
+    #[test]
+    fn normalizes_a_foreign_polygon_with_options() {
+        let (_, bad) = get_bad_outer_bad_inner_poly();
+        let opts = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_polygon_trait_with(&Columns::new(&bad), opts),
+            bad.normalized_with(opts)
+        );
+    }
+
+    #[test]
+    fn normalizes_a_multipolygon_through_its_traits() {
+        let (good, bad) = get_bad_outer_poly();
+        let (good_holed, bad_holed) = get_bad_outer_bad_inner_poly();
+        let multi = MultiPolygon(vec![bad, bad_holed]);
+        assert_eq!(
+            normalize_multi_polygon_trait(&multi),
+            MultiPolygon(vec![good, good_holed])
+        );
+    }
+
+    #[test]
+    fn a_missing_exterior_gives_an_empty_polygon() {
+        let empty = Polygon::<f64>::new(geo_types::LineString(vec![]), vec![]);
+        assert_eq!(normalize_polygon_trait(&empty), empty);
+    }
+}
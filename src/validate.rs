@@ -0,0 +1,256 @@
+use crate::{ring, Normalized, RingRole, WindingOrder};
+use alloc::vec::Vec;
+use geo_types::{Coord, CoordNum, LineString, MultiPolygon, Polygon, Rect};
+
+/// A way in which a polygon breaks the OGC simple feature rules, reported by [`validate_ogc`]
+///
+/// The ring `index` is `0` for the exterior and the position in [`Polygon::interiors`] for
+/// interior rings, as for [`Normalized::normalized_by`].
+///
+/// [`Normalized::normalized_by`]: crate::Normalized::normalized_by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OgcViolation {
+    /// The ring does not end at its first coordinate. geo closes the rings of a polygon when it
+    /// is built, so this is only found in polygons whose rings were changed afterwards.
+    UnclosedRing { role: RingRole, index: usize },
+    /// The ring has fewer than the four coordinates needed to enclose an area
+    TooFewPoints {
+        role: RingRole,
+        index: usize,
+        count: usize,
+    },
+    /// The ring has enough coordinates, but they are all collinear or enclose an area of
+    /// exactly zero, such as a figure of eight with equal lobes, so it has no winding to fix
+    UndefinedWinding { role: RingRole, index: usize },
+    /// The ring is wound the wrong way for its role
+    WrongWinding {
+        role: RingRole,
+        index: usize,
+        expected: WindingOrder,
+    },
+    /// The interior ring traces the same boundary as the exterior, in either direction, so the
+    /// polygon has no area whichever way the rings are wound. See
+    /// [`NormalizeOptions::drop_exterior_copies`].
+    ///
+    /// [`NormalizeOptions::drop_exterior_copies`]: crate::NormalizeOptions::drop_exterior_copies
+    InteriorEqualsExterior { index: usize },
+    /// The interior ring encloses a larger area than the exterior, so it cannot be a hole of
+    /// it, as when the rings were assigned the wrong roles. Normalization still winds the rings
+    /// by their stated roles. The polygon of a multipolygon member is given by
+    /// [`validate_ogc_multi`].
+    InteriorLargerThanExterior { index: usize },
+    /// The bounding boxes of the member polygons `a` and `b` of a multipolygon intersect or
+    /// touch, so their interiors may overlap, reported by [`validate_ogc_multi`]. This is a cheap
+    /// screen, not a proof: members whose boxes overlap can still be valid, such as a polygon
+    /// lying inside the hole of another, but members whose boxes are disjoint never overlap.
+    PossibleOverlap { a: usize, b: usize },
+}
+
+/// Report every way in which a polygon breaks the OGC rules for its rings, without changing it
+///
+/// Rings must be closed, have at least four coordinates, and follow the OGC winding: clockwise
+/// exterior and counter-clockwise interior rings. Interior rings must also differ from the
+/// exterior ring and enclose a smaller area than it, comparing unsigned areas. A polygon that
+/// passes is left unchanged by [`Normalized::normalized`]. Violations are listed ring by ring,
+/// exterior first.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::algorithm::winding_order::WindingOrder;
+/// use geo_normalized2::{validate_ogc, OgcViolation, RingRole};
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// assert_eq!(
+///     validate_ogc(&poly),
+///     vec![OgcViolation::WrongWinding {
+///         role: RingRole::Exterior,
+///         index: 0,
+///         expected: WindingOrder::Clockwise,
+///     }]
+/// );
+/// ```
+///
+/// [`Normalized::normalized`]: crate::Normalized::normalized
+pub fn validate_ogc<T: CoordNum>(poly: &Polygon<T>) -> Vec<OgcViolation> {
+    let mut violations = Vec::new();
+    validate_ring(poly.exterior(), RingRole::Exterior, 0, &mut violations);
+    for (index, ring) in poly.interiors().iter().enumerate() {
+        validate_ring(ring, RingRole::Interior, index, &mut violations);
+        if ring::is_same_boundary(poly.exterior(), ring) {
+            violations.push(OgcViolation::InteriorEqualsExterior { index });
+        } else if ring::abs(ring::twice_signed_area(ring))
+            > ring::abs(ring::twice_signed_area(poly.exterior()))
+        {
+            violations.push(OgcViolation::InteriorLargerThanExterior { index });
+        }
+    }
+    violations
+}
+
+/// Normalize a polygon with the OGC winding rules and report the violations that normalization
+/// could not fix, in a single call
+///
+/// The result is the same as [`Normalized::normalized`], and the violations are those
+/// [`validate_ogc`] finds in it. Normalization closes every ring and fixes the winding, so only
+/// the problems it leaves in place are reported: rings with too few points, rings without a
+/// defined winding, such as collinear rings or rings with zero area, and interior rings equal to
+/// or larger than the exterior. A ring is never reported as wrongly wound.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{normalize_and_validate, Normalized, OgcViolation, RingRole};
+/// let poly = polygon!(
+///     exterior: [
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///     ],
+///     interiors: [
+///         [(x: 2.0, y: 2.0), (x: 3.0, y: 2.0)],
+///     ],
+/// );
+///
+/// let (norm, violations) = normalize_and_validate(&poly);
+/// assert_eq!(norm, poly.normalized());
+/// assert_eq!(
+///     violations,
+///     vec![OgcViolation::TooFewPoints {
+///         role: RingRole::Interior,
+///         index: 0,
+///         count: 3,
+///     }]
+/// );
+/// ```
+///
+/// [`Normalized::normalized`]: crate::Normalized::normalized
+pub fn normalize_and_validate<T: CoordNum>(poly: &Polygon<T>) -> (Polygon<T>, Vec<OgcViolation>) {
+    let norm = poly.normalized();
+    let violations = validate_ogc(&norm);
+    (norm, violations)
+}
+
+/// Report every way in which a multipolygon breaks the OGC rules, without changing it
+///
+/// Each violation comes with the index of the member polygon it was found in. The ring
+/// violations of every member, as found by [`validate_ogc`], are listed first, in member order.
+/// They are followed by a [`PossibleOverlap`] for every pair of members whose bounding boxes
+/// intersect or touch, tagged with the first member of the pair. The overlap screen is a
+/// heuristic that flags multipolygons worth a closer look, it does not test whether the members
+/// actually overlap. Members with an empty exterior have no bounding box and are never flagged.
+/// Every pair of members is compared, so the screen takes quadratic time in the number of
+/// members.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon};
+/// use geo_normalized2::{validate_ogc_multi, OgcViolation};
+/// let square = |x: f64| {
+///     polygon![
+///         (x: x, y: 0.0),
+///         (x: x, y: 2.0),
+///         (x: x + 2.0, y: 2.0),
+///         (x: x + 2.0, y: 0.0),
+///         (x: x, y: 0.0),
+///     ]
+/// };
+/// let multi = MultiPolygon(vec![square(0.0), square(1.0), square(5.0)]);
+///
+/// assert_eq!(
+///     validate_ogc_multi(&multi),
+///     vec![(0, OgcViolation::PossibleOverlap { a: 0, b: 1 })]
+/// );
+/// ```
+///
+/// [`PossibleOverlap`]: OgcViolation::PossibleOverlap
+pub fn validate_ogc_multi<T: CoordNum>(multi: &MultiPolygon<T>) -> Vec<(usize, OgcViolation)> {
+    let mut violations: Vec<_> = multi
+        .0
+        .iter()
+        .enumerate()
+        .flat_map(|(i, poly)| validate_ogc(poly).into_iter().map(move |v| (i, v)))
+        .collect();
+    let boxes: Vec<Option<Rect<T>>> = multi.0.iter().map(bounding_rect).collect();
+    for (a, box_a) in boxes.iter().enumerate() {
+        for (b, box_b) in boxes.iter().enumerate().skip(a + 1) {
+            if let (Some(box_a), Some(box_b)) = (box_a, box_b) {
+                if rects_meet(box_a, box_b) {
+                    violations.push((a, OgcViolation::PossibleOverlap { a, b }));
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// The bounding box of the exterior ring of a polygon, as geo's `BoundingRect` finds it, or
+/// `None` when the exterior is empty
+fn bounding_rect<T: CoordNum>(poly: &Polygon<T>) -> Option<Rect<T>> {
+    let mut coords = poly.exterior().0.iter();
+    let first = *coords.next()?;
+    let min = |a: T, b: T| if a < b { a } else { b };
+    let max = |a: T, b: T| if a > b { a } else { b };
+    let (low, high) = coords.fold((first, first), |(low, high), c| {
+        (
+            Coord {
+                x: min(c.x, low.x),
+                y: min(c.y, low.y),
+            },
+            Coord {
+                x: max(c.x, high.x),
+                y: max(c.y, high.y),
+            },
+        )
+    });
+    Some(Rect::new(low, high))
+}
+
+/// Whether two rectangles intersect or touch
+fn rects_meet<T: CoordNum>(a: &Rect<T>, b: &Rect<T>) -> bool {
+    a.min().x <= b.max().x
+        && b.min().x <= a.max().x
+        && a.min().y <= b.max().y
+        && b.min().y <= a.max().y
+}
+
+fn validate_ring<T: CoordNum>(
+    ring: &LineString<T>,
+    role: RingRole,
+    index: usize,
+    violations: &mut Vec<OgcViolation>,
+) {
+    let count = ring.0.len();
+    if !ring.is_closed() {
+        violations.push(OgcViolation::UnclosedRing { role, index });
+    }
+    if count < 4 {
+        violations.push(OgcViolation::TooFewPoints { role, index, count });
+        return;
+    }
+    let expected = match role {
+        RingRole::Exterior => WindingOrder::Clockwise,
+        RingRole::Interior => WindingOrder::CounterClockwise,
+    };
+    // Rings with zero area are left as they are by normalization, whatever winding geo finds
+    match ring::winding_order(ring).filter(|_| !ring::has_zero_area(ring)) {
+        Some(winding) if winding != expected => violations.push(OgcViolation::WrongWinding {
+            role,
+            index,
+            expected,
+        }),
+        Some(_) => {}
+        None if ring.is_closed() => violations.push(OgcViolation::UndefinedWinding { role, index }),
+        None => {}
+    }
+}
@@ -0,0 +1,468 @@
+use crate::{ring, NormalizeOptions, Orientation};
+use alloc::vec::Vec;
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, LineString, MultiPolygon, Polygon, Triangle,
+};
+
+#[cfg(feature = "std")]
+pub use geo::algorithm::winding_order::WindingOrder;
+
+/// The direction in which a ring is wound
+///
+/// With the `std` feature this is geo's `WindingOrder`, re-exported so that the results of this
+/// crate compare with those of geo. Without `std` geo is not available, and this identical type
+/// takes its place.
+#[cfg(not(feature = "std"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindingOrder {
+    /// The ring turns clockwise, when `y` grows upwards
+    Clockwise,
+    /// The ring turns counter-clockwise, when `y` grows upwards
+    CounterClockwise,
+}
+
+/// The role a ring plays in its polygon, passed to the policy of [`Normalized::normalized_by`]
+///
+/// [`Normalized::normalized_by`]: crate::Normalized::normalized_by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingRole {
+    /// The outer boundary of a polygon
+    Exterior,
+    /// A hole of a polygon
+    Interior,
+}
+
+/// The winding order of the exterior ring of a polygon
+///
+/// Returns `None` when the ring has no defined winding, because it is not closed or has fewer
+/// than three distinct points.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo::algorithm::winding_order::WindingOrder;
+/// use geo_normalized2::exterior_winding;
+/// let poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// assert_eq!(exterior_winding(&poly), Some(WindingOrder::CounterClockwise));
+/// ```
+pub fn exterior_winding<T: CoordNum>(poly: &Polygon<T>) -> Option<WindingOrder> {
+    ring::winding_order(poly.exterior())
+}
+
+/// The winding order of each interior ring of a polygon, in the same order as
+/// [`Polygon::interiors`]
+///
+/// Rings without a defined winding are reported as `None`, see [`exterior_winding`].
+pub fn interior_windings<T: CoordNum>(poly: &Polygon<T>) -> Vec<Option<WindingOrder>> {
+    poly.interiors().iter().map(ring::winding_order).collect()
+}
+
+/// The signed area enclosed by a ring, by the shoelace formula
+///
+/// The area is positive for counter-clockwise rings, negative for clockwise rings and zero for
+/// empty or collinear rings. The ring is treated as closed whether or not it repeats its first
+/// coordinate, and nothing is allocated. For integer coordinates the area is halved with integer
+/// division, so it is truncated towards zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo_normalized2::ring_signed_area;
+/// let ring = line_string![
+///     (x: 1.0, y: 1.0),
+///     (x: 4.0, y: 1.0),
+///     (x: 4.0, y: 4.0),
+///     (x: 1.0, y: 4.0),
+///     (x: 1.0, y: 1.0),
+/// ];
+///
+/// assert_eq!(ring_signed_area(&ring), 9.0);
+/// ```
+pub fn ring_signed_area<T: CoordNum>(ring: &LineString<T>) -> T {
+    ring::twice_signed_area(ring) / (T::one() + T::one())
+}
+
+/// Normalize a polygon with the OGC winding rules and return it with its unsigned area
+///
+/// The area is the area of the exterior ring less the areas of the interior rings, each found
+/// with the shoelace formula like [`ring_signed_area`], so it matches geo's
+/// `Area::unsigned_area` for float coordinates. The signed area of
+/// each ring is found once, while it is normalized, and reused to tell whether the ring has zero
+/// area and so must not be reversed, instead of in a second pass over the normalized polygon.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area};
+/// use geo_normalized2::{normalized_with_area, Normalized};
+/// let poly = polygon!(
+///     exterior: [
+///         (x: 0.0, y: 0.0),
+///         (x: 4.0, y: 0.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 0.0, y: 4.0),
+///     ],
+///     interiors: [
+///         [(x: 1.0, y: 1.0), (x: 1.0, y: 2.0), (x: 2.0, y: 2.0), (x: 2.0, y: 1.0)],
+///     ],
+/// );
+///
+/// let (norm, area) = normalized_with_area(&poly);
+/// assert_eq!(norm, poly.normalized());
+/// assert_eq!(area, 15.0);
+/// assert_eq!(area, poly.unsigned_area());
+/// ```
+pub fn normalized_with_area<T: CoordNum>(poly: &Polygon<T>) -> (Polygon<T>, T) {
+    normalized_with_area_with(poly, NormalizeOptions::default())
+}
+
+/// Same as [`normalized_with_area`], normalizing as [`Normalized::normalized_with`] does
+///
+/// The polygon is always the one `normalized_with(opts)` returns, and the area is that of the
+/// returned polygon, so rings removed by the options are not counted.
+///
+/// [`Normalized::normalized_with`]: crate::Normalized::normalized_with
+pub fn normalized_with_area_with<T: CoordNum>(
+    poly: &Polygon<T>,
+    opts: NormalizeOptions<T>,
+) -> (Polygon<T>, T) {
+    let mut norm = poly.clone();
+    let mut area = T::zero();
+    crate::normalize_polygon_mut(&mut norm, opts, None, Some(&mut area));
+    (norm, area)
+}
+
+/// The winding order of the vertices of a triangle, using the same orientation test as for
+/// rings. Returns `None` for degenerate, collinear, triangles.
+pub(crate) fn triangle_winding<T: CoordNum>(tri: &Triangle<T>) -> Option<WindingOrder> {
+    ring::orientation(tri.v1(), tri.v2(), tri.v3())
+}
+
+/// Whether a triangle is wound as `wanted`, or is flat and so has no winding to fix
+pub(crate) fn triangle_matches<T: CoordNum>(
+    tri: &Triangle<T>,
+    wanted: Option<WindingOrder>,
+) -> bool {
+    match (triangle_winding(tri), wanted) {
+        (Some(winding), Some(wanted)) => winding == wanted,
+        _ => true,
+    }
+}
+
+/// Wind a single ring in the `wanted` order, in place, returning whether it was reversed
+///
+/// The ring is closed first if needed, then its coordinates are reversed within their own
+/// buffer when it winds the other way, so nothing is allocated and the ring stays closed, with
+/// the same first and last coordinate. Rings without a defined winding, or with a signed area of
+/// exactly zero, are left as they are. This is the step [`Normalized::normalize_mut`] applies to
+/// every ring through [`Polygon::exterior_mut`] and [`Polygon::interiors_mut`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::algorithm::winding_order::{Winding, WindingOrder};
+/// use geo_normalized2::wind_ring;
+/// let mut ring = line_string![
+///     (x: 1.0, y: 1.0),
+///     (x: 4.0, y: 1.0),
+///     (x: 4.0, y: 4.0),
+///     (x: 1.0, y: 4.0),
+/// ];
+///
+/// assert!(wind_ring(&mut ring, WindingOrder::Clockwise));
+/// assert!(ring.is_closed());
+/// assert_eq!(ring.winding_order(), Some(WindingOrder::Clockwise));
+/// assert!(!wind_ring(&mut ring, WindingOrder::Clockwise));
+/// ```
+///
+/// [`Normalized::normalize_mut`]: crate::Normalized::normalize_mut
+pub fn wind_ring<T: CoordNum>(ring: &mut LineString<T>, wanted: WindingOrder) -> bool {
+    ring.close();
+    ring::wind(ring, wanted)
+}
+
+/// A copy of a single ring, closed and wound in the `target` order
+///
+/// This is the owned counterpart of [`wind_ring`], for rings assembled before they are wrapped
+/// in a [`Polygon`], such as rings built up from [`Line`](geo_types::Line) segments. Rings without a
+/// defined winding are only closed.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geo::algorithm::winding_order::{Winding, WindingOrder};
+/// use geo_normalized2::normalize_ring;
+/// let ring = line_string![
+///     (x: 1.0, y: 1.0),
+///     (x: 4.0, y: 1.0),
+///     (x: 4.0, y: 4.0),
+/// ];
+///
+/// let wound = normalize_ring(&ring, WindingOrder::Clockwise);
+/// assert!(wound.is_closed());
+/// assert_eq!(wound.winding_order(), Some(WindingOrder::Clockwise));
+/// ```
+pub fn normalize_ring<T: CoordNum>(ring: &LineString<T>, target: WindingOrder) -> LineString<T> {
+    let mut ring = ring.clone();
+    wind_ring(&mut ring, target);
+    ring
+}
+
+/// Close an open track, such as a GPS track, into a single-ring polygon that follows the OGC
+/// winding rules
+///
+/// The track is closed by repeating its first coordinate when it does not already end there,
+/// then wound clockwise like [`wind_ring`], so the area enclosed by the track can be measured
+/// consistently whichever way it was walked.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Area;
+/// use geo::algorithm::winding_order::{Winding, WindingOrder};
+/// use geo_normalized2::{track_to_polygon, Normalized};
+/// let track = vec![(0.0, 0.0), (3.0, 0.0), (3.0, 2.0), (0.0, 2.0)];
+///
+/// let poly = track_to_polygon(track);
+/// assert!(poly.is_normalized());
+/// assert_eq!(poly.exterior().winding_order(), Some(WindingOrder::Clockwise));
+/// assert_eq!(poly.unsigned_area(), 6.0);
+/// ```
+pub fn track_to_polygon<T, I>(track: I) -> Polygon<T>
+where
+    T: CoordNum,
+    I: IntoIterator,
+    I::Item: Into<Coord<T>>,
+{
+    let mut ring: LineString<T> = track.into_iter().map(Into::into).collect();
+    wind_ring(&mut ring, WindingOrder::Clockwise);
+    Polygon::new(ring, Vec::new())
+}
+
+/// Check whether a geometry already follows a winding convention, without normalizing it
+pub trait MatchesOrientation {
+    /// Whether every ring is wound as `orientation` requires
+    ///
+    /// The exterior ring and all interior rings must have the winding the orientation asks for
+    /// their role. Rings without a winding to fix, those with a signed area of exactly zero or
+    /// too few distinct points, and flat triangles always match, since normalization leaves them
+    /// as they are. Polygons nested in collections at any depth and triangles are checked, other
+    /// geometries always match.
+    /// `matches(Orientation::Ogc)` is the same as [`Normalized::is_normalized`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use geo_normalized2::{MatchesOrientation, Orientation};
+    /// let poly = polygon![
+    ///         (x: 1.0, y: 1.0),
+    ///         (x: 4.0, y: 1.0),
+    ///         (x: 4.0, y: 4.0),
+    ///         (x: 1.0, y: 4.0),
+    ///         (x: 1.0, y: 1.0),
+    ///         ];
+    ///
+    /// assert!(poly.matches(Orientation::GeoJson));
+    /// assert!(!poly.matches(Orientation::Ogc));
+    /// ```
+    ///
+    /// [`Normalized::is_normalized`]: crate::Normalized::is_normalized
+    fn matches(&self, orientation: Orientation) -> bool;
+}
+
+impl<T: CoordNum> MatchesOrientation for Polygon<T> {
+    fn matches(&self, orientation: Orientation) -> bool {
+        if !ring::is_wound(self.exterior(), orientation.exterior_winding()) {
+            return false;
+        }
+        let interior = orientation.interior_winding_within(exterior_winding(self));
+        self.interiors()
+            .iter()
+            .all(|ring| ring::is_wound(ring, interior))
+    }
+}
+
+impl<T: CoordNum> MatchesOrientation for MultiPolygon<T> {
+    fn matches(&self, orientation: Orientation) -> bool {
+        self.0.iter().all(|poly| poly.matches(orientation))
+    }
+}
+
+impl<T: CoordNum> MatchesOrientation for Geometry<T> {
+    fn matches(&self, orientation: Orientation) -> bool {
+        match self {
+            Geometry::Polygon(poly) => poly.matches(orientation),
+            Geometry::MultiPolygon(mp) => mp.matches(orientation),
+            Geometry::GeometryCollection(gc) => gc.matches(orientation),
+            Geometry::Triangle(tri) => triangle_matches(tri, orientation.exterior_winding()),
+            _ => true,
+        }
+    }
+}
+
+impl<T: CoordNum> MatchesOrientation for GeometryCollection<T> {
+    fn matches(&self, orientation: Orientation) -> bool {
+        self.0.iter().all(|geom| geom.matches(orientation))
+    }
+}
+
+/// Reverse the rings of a polygon unconditionally, as building blocks for custom winding logic
+///
+/// Unlike [`Normalized::normalize_mut`], these always reverse, whatever the current winding.
+/// Closed rings stay closed, since their first and last coordinates swap places, and rings that
+/// were not closed are closed by geo afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use geo::polygon;
+/// use geo_normalized2::{Normalized, ReverseRings};
+/// let mut poly = polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 1.0, y: 1.0),
+///         ];
+///
+/// assert!(poly.is_normalized());
+/// poly.reverse_exterior();
+/// assert!(!poly.is_normalized());
+/// poly.reverse_exterior();
+/// assert!(poly.is_normalized());
+/// ```
+///
+/// [`Normalized::normalize_mut`]: crate::Normalized::normalize_mut
+pub trait ReverseRings {
+    /// Reverse the coordinate order of the exterior ring
+    fn reverse_exterior(&mut self);
+
+    /// Reverse the coordinate order of every interior ring
+    fn reverse_interiors(&mut self);
+}
+
+impl<T: CoordNum> ReverseRings for Polygon<T> {
+    fn reverse_exterior(&mut self) {
+        self.exterior_mut(|ring| ring.0.reverse());
+    }
+
+    fn reverse_interiors(&mut self) {
+        self.interiors_mut(|rings| {
+            for ring in rings {
+                ring.0.reverse();
+            }
+        });
+    }
+}
+
+/// Counts of ring windings, returned by [`WindingHistogram::winding_histogram`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WindingStats {
+    /// Exterior rings wound clockwise
+    pub cw_exteriors: usize,
+    /// Exterior rings wound counter-clockwise
+    pub ccw_exteriors: usize,
+    /// Interior rings wound clockwise
+    pub cw_interiors: usize,
+    /// Interior rings wound counter-clockwise
+    pub ccw_interiors: usize,
+    /// Rings of either role without a defined winding, see [`exterior_winding`]
+    pub undefined: usize,
+}
+
+impl WindingStats {
+    fn count(&mut self, winding: Option<WindingOrder>, role: RingRole) {
+        let count = match (winding, role) {
+            (Some(WindingOrder::Clockwise), RingRole::Exterior) => &mut self.cw_exteriors,
+            (Some(WindingOrder::CounterClockwise), RingRole::Exterior) => &mut self.ccw_exteriors,
+            (Some(WindingOrder::Clockwise), RingRole::Interior) => &mut self.cw_interiors,
+            (Some(WindingOrder::CounterClockwise), RingRole::Interior) => &mut self.ccw_interiors,
+            (None, _) => &mut self.undefined,
+        };
+        *count += 1;
+    }
+
+    fn add_polygon<T: CoordNum>(&mut self, poly: &Polygon<T>) {
+        self.count(exterior_winding(poly), RingRole::Exterior);
+        for ring in poly.interiors() {
+            self.count(ring::winding_order(ring), RingRole::Interior);
+        }
+    }
+
+    fn add_geometry<T: CoordNum>(&mut self, geom: &Geometry<T>) {
+        match geom {
+            Geometry::Polygon(poly) => self.add_polygon(poly),
+            Geometry::MultiPolygon(mp) => mp.0.iter().for_each(|poly| self.add_polygon(poly)),
+            Geometry::GeometryCollection(gc) => gc.0.iter().for_each(|g| self.add_geometry(g)),
+            _ => {}
+        }
+    }
+}
+
+/// Tally the windings of the rings of a geometry without changing it, to profile a dataset
+/// before deciding whether it needs normalizing
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, MultiPolygon};
+/// use geo_normalized2::{WindingHistogram, WindingStats};
+/// let mp = MultiPolygon(vec![polygon![
+///         (x: 1.0, y: 1.0),
+///         (x: 4.0, y: 1.0),
+///         (x: 4.0, y: 4.0),
+///         (x: 1.0, y: 4.0),
+///         (x: 1.0, y: 1.0),
+///         ]]);
+///
+/// assert_eq!(mp.winding_histogram(), WindingStats { ccw_exteriors: 1, ..Default::default() });
+/// ```
+pub trait WindingHistogram {
+    /// Count the exterior and interior rings wound each way. Polygons nested in collections at
+    /// any depth are counted, other geometries are skipped.
+    fn winding_histogram(&self) -> WindingStats;
+}
+
+impl<T: CoordNum> WindingHistogram for Polygon<T> {
+    fn winding_histogram(&self) -> WindingStats {
+        let mut stats = WindingStats::default();
+        stats.add_polygon(self);
+        stats
+    }
+}
+
+impl<T: CoordNum> WindingHistogram for MultiPolygon<T> {
+    fn winding_histogram(&self) -> WindingStats {
+        let mut stats = WindingStats::default();
+        self.0.iter().for_each(|poly| stats.add_polygon(poly));
+        stats
+    }
+}
+
+impl<T: CoordNum> WindingHistogram for Geometry<T> {
+    fn winding_histogram(&self) -> WindingStats {
+        let mut stats = WindingStats::default();
+        stats.add_geometry(self);
+        stats
+    }
+}
+
+impl<T: CoordNum> WindingHistogram for GeometryCollection<T> {
+    fn winding_histogram(&self) -> WindingStats {
+        let mut stats = WindingStats::default();
+        self.0.iter().for_each(|geom| stats.add_geometry(geom));
+        stats
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{normalize_multi_polygon_trait_with, normalize_polygon_trait_with};
+use crate::{NormalizeOptions, Normalized, Orientation};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::fmt::Write;
+use geo_traits::to_geo::ToGeoGeometryCollection;
+use geo_traits::{Dimensions, GeometryTrait, GeometryType};
+use wkb::reader::read_wkb;
+use wkb::writer::{write_geometry, WriteOptions};
+use wkb::Endianness;
+
+/// An error from reading or writing a WKB blob
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid WKB: {message}")]
+pub struct WkbError {
+    /// What was wrong with the input
+    pub message: String,
+}
+
+fn error(message: impl ToString) -> WkbError {
+    WkbError {
+        message: message.to_string(),
+    }
+}
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+/// How deep multi geometries and geometry collections may be nested
+const MAX_DEPTH: usize = 128;
+
+/// Normalize the polygon winding of a WKB or EWKB blob with the OGC winding rules
+///
+/// The blob is decoded and re-encoded with the [`wkb`](https://crates.io/crates/wkb) crate.
+/// POLYGON, MULTIPOLYGON and GEOMETRYCOLLECTION geometries are normalized and written back in
+/// the byte order of the input, with its EWKB SRID, if any. All other geometry types are copied
+/// unchanged. Only two dimensional polygons are supported, since normalization goes through
+/// geo-types, and collections may not contain empty points. More than 128 levels of nested
+/// multi geometries and collections are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_wkb;
+///
+/// // A little endian, counter-clockwise POLYGON((1 1,4 1,4 4,1 1))
+/// let mut wkb = vec![1, 3, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0];
+/// for v in [1.0f64, 1.0, 4.0, 1.0, 4.0, 4.0, 1.0, 1.0] {
+///     wkb.extend_from_slice(&v.to_le_bytes());
+/// }
+///
+/// let norm = normalize_wkb(&wkb).unwrap();
+/// assert_eq!(norm[13..21], 1.0f64.to_le_bytes());
+/// assert_eq!(norm[29..37], 4.0f64.to_le_bytes());
+/// assert_eq!(norm[37..45], 4.0f64.to_le_bytes());
+/// ```
+pub fn normalize_wkb(bytes: &[u8]) -> Result<Vec<u8>, WkbError> {
+    normalize_wkb_with(bytes, Orientation::Ogc)
+}
+
+/// Same as [`normalize_wkb`], producing the winding of the given `orientation`
+pub fn normalize_wkb_with(bytes: &[u8], orientation: Orientation) -> Result<Vec<u8>, WkbError> {
+    // The wkb crate reads nested collections recursively, without a limit of its own
+    check_depth(bytes)?;
+    let geom = read_wkb(bytes).map_err(error)?;
+    if geom.buf().len() < bytes.len() {
+        return Err(error("unexpected trailing input"));
+    }
+    let opts = NormalizeOptions {
+        orientation,
+        ..Default::default()
+    };
+    let endianness = match bytes[0] {
+        0 => Endianness::BigEndian,
+        _ => Endianness::LittleEndian,
+    };
+    let options = WriteOptions { endianness };
+    let mut out = Vec::with_capacity(bytes.len());
+    let written = match geom.as_type() {
+        GeometryType::Polygon(poly) => {
+            check_xy(&geom)?;
+            write_geometry(
+                &mut out,
+                &normalize_polygon_trait_with(poly, opts),
+                &options,
+            )
+        }
+        GeometryType::MultiPolygon(multi) => {
+            check_xy(&geom)?;
+            write_geometry(
+                &mut out,
+                &normalize_multi_polygon_trait_with(multi, opts),
+                &options,
+            )
+        }
+        GeometryType::GeometryCollection(collection) => {
+            check_xy(&geom)?;
+            let mut collection = collection
+                .try_to_geometry_collection()
+                .ok_or_else(|| error("empty points in a collection are not supported"))?;
+            collection.normalize_mut_with(opts);
+            write_geometry(&mut out, &collection, &options)
+        }
+        _ => return Ok(bytes.to_vec()),
+    };
+    written.map_err(error)?;
+    let code = u32_at(bytes, 1, endianness);
+    if code & EWKB_SRID != 0 {
+        // The wkb crate writes plain WKB, so the SRID of the input is put back in its header
+        let code = u32_at(&out, 1, endianness) | EWKB_SRID;
+        out[1..5].copy_from_slice(&u32_bytes(code, endianness));
+        out.splice(5..5, bytes[5..9].iter().copied());
+    }
+    Ok(out)
+}
+
+/// Normalize hex encoded WKB or EWKB, as returned by PostGIS `ST_AsEWKB`, with the OGC winding
+/// rules
+///
+/// This is [`normalize_wkb`] for hex strings, so the SRID and byte order are kept. Both upper and
+/// lower case hex digits are accepted; the output uses upper case, like PostGIS.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_ewkb_hex;
+///
+/// // SRID=4326;POINT(1 2) is passed through unchanged
+/// let hex = "0101000020E6100000000000000000F03F0000000000000040";
+/// assert_eq!(normalize_ewkb_hex(hex).unwrap(), hex);
+/// ```
+pub fn normalize_ewkb_hex(hex: &str) -> Result<String, WkbError> {
+    let hex = hex.trim();
+    if !hex.len().is_multiple_of(2) {
+        return Err(error("hex input has an odd number of digits"));
+    }
+    let bytes = hex
+        .as_bytes()
+        .chunks(2)
+        .enumerate()
+        .map(|(position, pair)| {
+            // Each digit is checked on its own, `from_str_radix` would also accept a sign
+            let digit = |byte: u8| char::from(byte).to_digit(16);
+            digit(pair[0])
+                .zip(digit(pair[1]))
+                .map(|(high, low)| (high * 16 + low) as u8)
+                .ok_or_else(|| error(format!("invalid hex digit at byte {}", position)))
+        })
+        .collect::<Result<Vec<u8>, WkbError>>()?;
+    let mut out = String::with_capacity(hex.len());
+    for byte in normalize_wkb(&bytes)? {
+        let _ = write!(out, "{:02X}", byte);
+    }
+    Ok(out)
+}
+
+/// Reject geometries with Z or M values, which would be lost on the way through geo-types
+fn check_xy(geom: &impl GeometryTrait) -> Result<(), WkbError> {
+    match geom.dim() {
+        Dimensions::Xy => Ok(()),
+        _ => Err(error("only two dimensional polygons are supported")),
+    }
+}
+
+fn u32_at(bytes: &[u8], at: usize, endianness: Endianness) -> u32 {
+    let word = bytes[at..at + 4].try_into().unwrap();
+    match endianness {
+        Endianness::BigEndian => u32::from_be_bytes(word),
+        Endianness::LittleEndian => u32::from_le_bytes(word),
+    }
+}
+
+fn u32_bytes(value: u32, endianness: Endianness) -> [u8; 4] {
+    match endianness {
+        Endianness::BigEndian => value.to_be_bytes(),
+        Endianness::LittleEndian => value.to_le_bytes(),
+    }
+}
+
+/// Check that multi geometries and collections are nested at most [`MAX_DEPTH`] levels deep,
+/// skipping over everything else. Malformed input is left for the wkb crate to report.
+fn check_depth(bytes: &[u8]) -> Result<(), WkbError> {
+    // The number of members left to read in each open multi geometry or collection
+    let mut levels: Vec<usize> = Vec::new();
+    let mut pos = 0;
+    loop {
+        let endianness = match bytes.get(pos) {
+            Some(0) => Endianness::BigEndian,
+            Some(1) => Endianness::LittleEndian,
+            _ => return Ok(()),
+        };
+        let word = |at: usize| {
+            bytes
+                .get(at..at.saturating_add(4))
+                .map(|_| u32_at(bytes, at, endianness))
+        };
+        let Some(code) = word(pos + 1) else {
+            return Ok(());
+        };
+        pos += if code & EWKB_SRID != 0 { 9 } else { 5 };
+        let base = code & 0x0fff_ffff;
+        let z = matches!(base / 1000, 1 | 3) || code & EWKB_Z != 0;
+        let m = matches!(base / 1000, 2 | 3) || code & EWKB_M != 0;
+        let stride = 8 * (2 + usize::from(z) + usize::from(m));
+        let Some(count) = word(pos) else {
+            return Ok(());
+        };
+        let count = count as usize;
+        match base % 1000 {
+            1 => pos += stride,
+            2 => pos = pos.saturating_add(4 + count.saturating_mul(stride)),
+            3 => {
+                pos += 4;
+                for _ in 0..count {
+                    let Some(len) = word(pos) else {
+                        return Ok(());
+                    };
+                    pos = pos.saturating_add(4 + (len as usize).saturating_mul(stride));
+                }
+            }
+            4..=7 => {
+                if levels.len() == MAX_DEPTH {
+                    return Err(error("geometries are nested too deeply"));
+                }
+                pos += 4;
+                if count > 0 {
+                    levels.push(count);
+                    continue;
+                }
+            }
+            _ => return Ok(()),
+        }
+        // The geometry is read, which may be the last member of the levels around it
+        loop {
+            let Some(left) = levels.last_mut() else {
+                return Ok(());
+            };
+            *left -= 1;
+            if *left > 0 {
+                break;
+            }
+            levels.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{get_bad_outer_bad_inner_poly, get_bad_outer_poly};
+    use crate::Normalized;
+    use geo::Polygon;
+
+    /// Encode a polygon as WKB, with an optional EWKB SRID
+    fn polygon_wkb(poly: &Polygon<f64>, little_endian: bool, srid: Option<u32>) -> Vec<u8> {
+        let u32_bytes = |v: u32| {
+            if little_endian {
+                v.to_le_bytes()
+            } else {
+                v.to_be_bytes()
+            }
+        };
+        let mut out = vec![u8::from(little_endian)];
+        match srid {
+            Some(srid) => {
+                out.extend_from_slice(&u32_bytes(3 | 0x2000_0000));
+                out.extend_from_slice(&u32_bytes(srid));
+            }
+            None => out.extend_from_slice(&u32_bytes(3)),
+        }
+        out.extend_from_slice(&u32_bytes(1 + poly.interiors().len() as u32));
+        for ring in core::iter::once(poly.exterior()).chain(poly.interiors()) {
+            out.extend_from_slice(&u32_bytes(ring.0.len() as u32));
+            for c in &ring.0 {
+                for v in [c.x, c.y] {
+                    out.extend_from_slice(&if little_endian {
+                        v.to_le_bytes()
+                    } else {
+                        v.to_be_bytes()
+                    });
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn can_normalize_wkb_polygons() {
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        assert_eq!(
+            normalize_wkb(&polygon_wkb(&bad, true, None)),
+            Ok(polygon_wkb(&good, true, None))
+        );
+        assert_eq!(
+            normalize_wkb(&polygon_wkb(&bad, false, Some(4326))),
+            Ok(polygon_wkb(&good, false, Some(4326)))
+        );
+        assert_eq!(
+            normalize_wkb_with(&polygon_wkb(&good, true, None), Orientation::GeoJson),
+            Ok(polygon_wkb(&bad, true, None))
+        );
+    }
+
+    #[test]
+    fn can_normalize_wkb_multi_polygons() {
+        let (good, bad) = get_bad_outer_poly();
+        let collection = |polys: [&Polygon<f64>; 2]| {
+            let mut out = vec![1, 6, 0, 0, 0, 2, 0, 0, 0];
+            for poly in polys {
+                out.extend(polygon_wkb(poly, true, None));
+            }
+            out
+        };
+        assert_eq!(
+            normalize_wkb(&collection([&bad, &good])),
+            Ok(collection([&good, &good]))
+        );
+    }
+
+    #[test]
+    fn can_normalize_ewkb_hex() {
+        let hex = |bytes: Vec<u8>| {
+            bytes
+                .iter()
+                .map(|b| format!("{:02X}", b))
+                .collect::<String>()
+        };
+        let (good, bad) = get_bad_outer_bad_inner_poly();
+        for little_endian in [true, false] {
+            let input = hex(polygon_wkb(&bad, little_endian, Some(4326)));
+            let expected = hex(polygon_wkb(&good, little_endian, Some(4326)));
+            assert_eq!(normalize_ewkb_hex(&input), Ok(expected.clone()));
+            assert_eq!(
+                normalize_ewkb_hex(&input.to_ascii_lowercase()),
+                Ok(expected)
+            );
+        }
+        assert_eq!(
+            normalize_ewkb_hex("0101000").unwrap_err().message,
+            "hex input has an odd number of digits"
+        );
+        assert_eq!(
+            normalize_ewkb_hex("01ZZ").unwrap_err().message,
+            "invalid hex digit at byte 1"
+        );
+        // `u8::from_str_radix` reads "+1" as 1, a sign is not a hex digit
+        assert_eq!(
+            normalize_ewkb_hex("+101000000").unwrap_err().message,
+            "invalid hex digit at byte 0"
+        );
+    }
+
+    #[test]
+    fn wkb_opposite_interior_keeps_exterior() {
+        let (good, _) = get_bad_outer_bad_inner_poly();
+        let ccw = good.denormalized();
+        let mut ccw_bad_hole = ccw.clone();
+        ccw_bad_hole.interiors_mut(|rings| rings[0] = good.interiors()[0].clone());
+        assert_eq!(
+            normalize_wkb_with(
+                &polygon_wkb(&ccw_bad_hole, true, None),
+                Orientation::OppositeInterior
+            ),
+            Ok(polygon_wkb(&ccw, true, None))
+        );
+    }
+
+    #[test]
+    fn passes_through_wkb_points_and_rejects_bad_input() {
+        let mut point = vec![1, 1, 0, 0, 0];
+        point.extend_from_slice(&1.5f64.to_le_bytes());
+        point.extend_from_slice(&2.5f64.to_le_bytes());
+        assert_eq!(normalize_wkb(&point), Ok(point.clone()));
+
+        assert!(normalize_wkb(&point[..12]).is_err());
+        assert!(normalize_wkb(&[2]).is_err());
+        assert!(normalize_wkb(&[1, 8, 0, 0, 0]).is_err());
+        let mut trailing = point.clone();
+        trailing.push(0);
+        assert_eq!(
+            normalize_wkb(&trailing).unwrap_err().message,
+            "unexpected trailing input"
+        );
+    }
+
+    #[test]
+    fn rejects_polygons_with_z_values() {
+        // POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1)), with the ISO type code
+        let mut wkb = vec![1, 0xEB, 0x03, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0];
+        for v in [0.0f64, 0., 1., 1., 0., 1., 1., 1., 1., 0., 0., 1.] {
+            wkb.extend_from_slice(&v.to_le_bytes());
+        }
+        assert_eq!(
+            normalize_wkb(&wkb).unwrap_err().message,
+            "only two dimensional polygons are supported"
+        );
+    }
+
+    #[test]
+    fn rejects_deeply_nested_collections() {
+        let nested = |depth: usize| {
+            let mut out = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(depth);
+            out.extend([1, 7, 0, 0, 0, 0, 0, 0, 0]);
+            out
+        };
+        let wkb = nested(MAX_DEPTH - 1);
+        assert_eq!(normalize_wkb(&wkb), Ok(wkb));
+        let err = normalize_wkb(&nested(MAX_DEPTH)).unwrap_err();
+        assert_eq!(err.message, "geometries are nested too deeply");
+        let err = normalize_wkb(&nested(200_000)).unwrap_err();
+        assert_eq!(err.message, "geometries are nested too deeply");
+    }
+
+    #[test]
+    fn counts_the_members_of_nested_collections() {
+        let (good, bad) = get_bad_outer_poly();
+        // GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(POLYGON, POLYGON), POLYGON)
+        let collection = |polys: [&Polygon<f64>; 3]| {
+            let mut out = vec![1, 7, 0, 0, 0, 2, 0, 0, 0, 1, 7, 0, 0, 0, 2, 0, 0, 0];
+            for poly in polys {
+                out.extend(polygon_wkb(poly, true, None));
+            }
+            out
+        };
+        assert_eq!(
+            normalize_wkb(&collection([&bad, &good, &bad])),
+            Ok(collection([&good, &good, &good]))
+        );
+    }
+}
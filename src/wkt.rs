@@ -0,0 +1,518 @@
+use crate::{NormalizeOptions, Normalized};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use geo_types::{
+    Coord, Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+
+/// How deep geometry collections may be nested
+const MAX_DEPTH: usize = 128;
+
+/// An error from reading a WKT string
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid WKT at byte {position}: {message}")]
+pub struct WktError {
+    /// What was wrong with the input
+    pub message: String,
+    /// The byte offset in the input where the problem was found
+    pub position: usize,
+}
+
+/// Parse a WKT geometry, normalize it with the OGC winding rules and write it back out as WKT
+///
+/// POLYGON, MULTIPOLYGON and GEOMETRYCOLLECTION inputs are normalized, all other geometry types
+/// are written back unchanged. Only two dimensional coordinates are supported, and more than 128
+/// levels of nested geometry collections are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_wkt;
+///
+/// let norm = normalize_wkt("POLYGON ((1 1, 4 1, 4 4, 1 4, 1 1))").unwrap();
+/// assert_eq!(norm, "POLYGON((1 1,1 4,4 4,4 1,1 1))");
+/// ```
+pub fn normalize_wkt(input: &str) -> Result<String, WktError> {
+    normalize_wkt_with(input, NormalizeOptions::default())
+}
+
+/// Same as [`normalize_wkt`], normalizing with the settings given in `opts`
+pub fn normalize_wkt_with(input: &str, opts: NormalizeOptions<f64>) -> Result<String, WktError> {
+    let mut geom = read_wkt(input)?;
+    geom.normalize_mut_with(opts);
+    Ok(write_wkt(&geom))
+}
+
+/// Normalize a stream of WKT geometries, one per line, with the OGC winding rules
+///
+/// Each line of `reader` is normalized like [`normalize_wkt`] and written to `writer` with its
+/// original line ending. Blank lines and comment lines, whose first non-blank character is `#`,
+/// are written back unchanged.
+///
+/// A line that is not valid WKT stops the stream with an error of kind [`InvalidData`] wrapping
+/// the [`WktError`], unless `collect_errors` is set. In that case the line is written back
+/// unchanged and its 1-based line number and error are returned once the stream is done.
+///
+/// # Examples
+///
+/// ```
+/// use geo_normalized2::normalize_wkt_lines;
+///
+/// let input = "# parcels\nPOLYGON ((1 1, 4 1, 4 4, 1 4, 1 1))\n\nPOLYGON ((1 1\n";
+/// let mut output = Vec::new();
+/// let errors = normalize_wkt_lines(input.as_bytes(), &mut output, true).unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "# parcels\nPOLYGON((1 1,1 4,4 4,4 1,1 1))\n\nPOLYGON ((1 1\n"
+/// );
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, 4);
+/// ```
+///
+/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+#[cfg(feature = "std")]
+pub fn normalize_wkt_lines<R: std::io::BufRead, W: std::io::Write>(
+    reader: R,
+    writer: W,
+    collect_errors: bool,
+) -> std::io::Result<Vec<(usize, WktError)>> {
+    normalize_wkt_lines_with(reader, writer, NormalizeOptions::default(), collect_errors)
+}
+
+/// Same as [`normalize_wkt_lines`], normalizing with the settings given in `opts`
+#[cfg(feature = "std")]
+pub fn normalize_wkt_lines_with<R: std::io::BufRead, W: std::io::Write>(
+    mut reader: R,
+    mut writer: W,
+    opts: NormalizeOptions<f64>,
+    collect_errors: bool,
+) -> std::io::Result<Vec<(usize, WktError)>> {
+    let mut errors = Vec::new();
+    let mut line = String::new();
+    let mut number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        number += 1;
+        let text = line.trim_end_matches(['\n', '\r']);
+        let ending = &line[text.len()..];
+        let trimmed = text.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            writer.write_all(line.as_bytes())?;
+        } else {
+            match normalize_wkt_with(text, opts) {
+                Ok(norm) => {
+                    writer.write_all(norm.as_bytes())?;
+                    writer.write_all(ending.as_bytes())?;
+                }
+                Err(err) if collect_errors => {
+                    errors.push((number, err));
+                    writer.write_all(line.as_bytes())?;
+                }
+                Err(err) => {
+                    return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, err));
+                }
+            }
+        }
+        line.clear();
+    }
+    writer.flush()?;
+    Ok(errors)
+}
+
+/// Parse a single two dimensional WKT geometry
+pub(crate) fn read_wkt(input: &str) -> Result<Geometry<f64>, WktError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    let geom = parser.geometry()?;
+    parser.skip_whitespace();
+    if parser.pos < input.len() {
+        return Err(parser.error("unexpected trailing input"));
+    }
+    Ok(geom)
+}
+
+/// Write a geometry as WKT
+pub(crate) fn write_wkt(geom: &Geometry<f64>) -> String {
+    let mut out = String::new();
+    write_geometry(&mut out, geom);
+    out
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// The number of geometry collections the parser is inside of
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> WktError {
+        WktError {
+            message: message.to_string(),
+            position: self.pos,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), WktError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", byte as char)))
+        }
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len].to_ascii_uppercase()
+    }
+
+    fn number(&mut self) -> Result<f64, WktError> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+'))
+            .unwrap_or(rest.len());
+        let value = rest[..len]
+            .parse::<f64>()
+            .map_err(|_| self.error("expected a number"))?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    /// Consume `EMPTY` if it comes next, otherwise the opening parenthesis of the body
+    fn is_empty(&mut self) -> Result<bool, WktError> {
+        if self.peek() == Some(b'(') {
+            self.pos += 1;
+            return Ok(false);
+        }
+        match self.word().as_str() {
+            "EMPTY" => Ok(true),
+            "Z" | "M" | "ZM" => Err(self.error("only two dimensional coordinates are supported")),
+            _ => Err(self.error("expected '(' or EMPTY")),
+        }
+    }
+
+    /// Parse comma separated items up to and including the closing parenthesis
+    fn list<I>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<I, WktError>,
+    ) -> Result<Vec<I>, WktError> {
+        let mut items = vec![item(self)?];
+        while self.peek() == Some(b',') {
+            self.pos += 1;
+            items.push(item(self)?);
+        }
+        self.expect(b')')?;
+        Ok(items)
+    }
+
+    fn coord(&mut self) -> Result<Coord<f64>, WktError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(Coord { x, y })
+    }
+
+    fn line_string(&mut self) -> Result<LineString<f64>, WktError> {
+        if self.is_empty()? {
+            return Ok(LineString::new(vec![]));
+        }
+        Ok(LineString::new(self.list(Self::coord)?))
+    }
+
+    fn polygon(&mut self) -> Result<Polygon<f64>, WktError> {
+        if self.is_empty()? {
+            return Ok(Polygon::new(LineString::new(vec![]), vec![]));
+        }
+        let mut rings = self.list(Self::line_string)?;
+        let exterior = rings.remove(0);
+        Ok(Polygon::new(exterior, rings))
+    }
+
+    /// MULTIPOINT members may or may not be wrapped in their own parentheses
+    fn multi_point_member(&mut self) -> Result<Point<f64>, WktError> {
+        if self.peek() == Some(b'(') {
+            self.pos += 1;
+            let coord = self.coord()?;
+            self.expect(b')')?;
+            Ok(Point(coord))
+        } else {
+            Ok(Point(self.coord()?))
+        }
+    }
+
+    fn geometry(&mut self) -> Result<Geometry<f64>, WktError> {
+        let start = self.pos;
+        let geom = match self.word().as_str() {
+            "POINT" => {
+                if self.is_empty()? {
+                    return Err(self.error("POINT EMPTY is not supported"));
+                }
+                let coord = self.coord()?;
+                self.expect(b')')?;
+                Geometry::Point(Point(coord))
+            }
+            "LINESTRING" => Geometry::LineString(self.line_string()?),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => Geometry::MultiPoint(MultiPoint(if self.is_empty()? {
+                vec![]
+            } else {
+                self.list(Self::multi_point_member)?
+            })),
+            "MULTILINESTRING" => Geometry::MultiLineString(MultiLineString(if self.is_empty()? {
+                vec![]
+            } else {
+                self.list(Self::line_string)?
+            })),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(MultiPolygon(if self.is_empty()? {
+                vec![]
+            } else {
+                self.list(Self::polygon)?
+            })),
+            "GEOMETRYCOLLECTION" => {
+                if self.depth == MAX_DEPTH {
+                    self.pos = start;
+                    return Err(self.error("geometries are nested too deeply"));
+                }
+                self.depth += 1;
+                let members = if self.is_empty()? {
+                    vec![]
+                } else {
+                    self.list(Self::geometry)?
+                };
+                self.depth -= 1;
+                Geometry::GeometryCollection(GeometryCollection(members))
+            }
+            _ => {
+                self.pos = start;
+                return Err(self.error("unknown geometry type"));
+            }
+        };
+        Ok(geom)
+    }
+}
+
+/// EMPTY follows a geometry type after a space, but not when it is a member of a list
+fn push_empty(out: &mut String) {
+    if !out.ends_with(['(', ',']) {
+        out.push(' ');
+    }
+    out.push_str("EMPTY");
+}
+
+fn write_coords<'a>(out: &mut String, coords: impl Iterator<Item = &'a Coord<f64>>) {
+    out.push('(');
+    for (i, c) in coords.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{} {}", c.x, c.y);
+    }
+    out.push(')');
+}
+
+fn write_members<I>(out: &mut String, items: &[I], mut item: impl FnMut(&mut String, &I)) {
+    if items.is_empty() {
+        push_empty(out);
+        return;
+    }
+    out.push('(');
+    for (i, member) in items.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        item(out, member);
+    }
+    out.push(')');
+}
+
+fn write_line_string(out: &mut String, line: &LineString<f64>) {
+    if line.0.is_empty() {
+        push_empty(out);
+    } else {
+        write_coords(out, line.0.iter());
+    }
+}
+
+fn write_polygon(out: &mut String, poly: &Polygon<f64>) {
+    if poly.exterior().0.is_empty() {
+        push_empty(out);
+        return;
+    }
+    out.push('(');
+    write_coords(out, poly.exterior().0.iter());
+    for ring in poly.interiors() {
+        out.push(',');
+        write_coords(out, ring.0.iter());
+    }
+    out.push(')');
+}
+
+fn write_geometry(out: &mut String, geom: &Geometry<f64>) {
+    match geom {
+        Geometry::Point(p) => {
+            out.push_str("POINT");
+            write_coords(out, core::iter::once(&p.0));
+        }
+        Geometry::Line(l) => {
+            out.push_str("LINESTRING");
+            write_coords(out, [l.start, l.end].iter());
+        }
+        Geometry::LineString(l) => {
+            out.push_str("LINESTRING");
+            write_line_string(out, l);
+        }
+        Geometry::Polygon(p) => {
+            out.push_str("POLYGON");
+            write_polygon(out, p);
+        }
+        Geometry::MultiPoint(mp) => {
+            out.push_str("MULTIPOINT");
+            write_members(out, &mp.0, |out, p| {
+                write_coords(out, core::iter::once(&p.0))
+            });
+        }
+        Geometry::MultiLineString(ml) => {
+            out.push_str("MULTILINESTRING");
+            write_members(out, &ml.0, write_line_string);
+        }
+        Geometry::MultiPolygon(mp) => {
+            out.push_str("MULTIPOLYGON");
+            write_members(out, &mp.0, write_polygon);
+        }
+        Geometry::GeometryCollection(gc) => {
+            out.push_str("GEOMETRYCOLLECTION");
+            write_members(out, &gc.0, write_geometry);
+        }
+        Geometry::Rect(r) => write_geometry(out, &Geometry::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => write_geometry(out, &Geometry::Polygon(t.to_polygon())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_normalize_wkt() {
+        assert_eq!(
+            normalize_wkt("POLYGON((0 0,50 0,50 50,0 50,0 0),(10 10,10 20,20 20,20 10,10 10))"),
+            Ok("POLYGON((0 0,0 50,50 50,50 0,0 0),(10 10,20 10,20 20,10 20,10 10))".to_string())
+        );
+        assert_eq!(
+            normalize_wkt("multipolygon (((1 1, 4 1, 4 4, 1 4, 1 1)), EMPTY)"),
+            Ok("MULTIPOLYGON(((1 1,1 4,4 4,4 1,1 1)),EMPTY)".to_string())
+        );
+        assert_eq!(
+            normalize_wkt(
+                "GEOMETRYCOLLECTION (POINT (1.5 2), POLYGON ((1 1, 4 1, 4 4, 1 4, 1 1)))"
+            ),
+            Ok("GEOMETRYCOLLECTION(POINT(1.5 2),POLYGON((1 1,1 4,4 4,4 1,1 1)))".to_string())
+        );
+    }
+
+    #[test]
+    fn wkt_passes_non_areal_geometries_through() {
+        for wkt in [
+            "POINT(1 2)",
+            "LINESTRING(1 1,4 1,4 4,1 1)",
+            "MULTIPOINT((1 2),(3 4))",
+            "MULTILINESTRING((1 1,2 2),(3 3,4 4))",
+            "GEOMETRYCOLLECTION EMPTY",
+            "POLYGON EMPTY",
+        ] {
+            assert_eq!(normalize_wkt(wkt), Ok(wkt.to_string()));
+        }
+        assert_eq!(
+            normalize_wkt("MULTIPOINT (1 2, 3 4)"),
+            Ok("MULTIPOINT((1 2),(3 4))".to_string())
+        );
+    }
+
+    #[test]
+    fn wkt_reports_parse_errors() {
+        assert_eq!(normalize_wkt("CIRCLE(1 1)").unwrap_err().position, 0);
+        assert!(normalize_wkt("POLYGON((1 1,4 1,4 4,1 1)").is_err());
+        assert!(normalize_wkt("POINT Z (1 1 1)").is_err());
+        assert!(normalize_wkt("POINT(1 1) POINT(2 2)").is_err());
+    }
+
+    #[test]
+    fn rejects_deeply_nested_collections() {
+        let nested = |depth: usize| {
+            format!(
+                "{}GEOMETRYCOLLECTION EMPTY{}",
+                "GEOMETRYCOLLECTION(".repeat(depth),
+                ")".repeat(depth)
+            )
+        };
+        let wkt = nested(MAX_DEPTH - 1);
+        assert_eq!(normalize_wkt(&wkt), Ok(wkt));
+        assert_eq!(
+            normalize_wkt(&nested(MAX_DEPTH)),
+            Err(WktError {
+                message: "geometries are nested too deeply".to_string(),
+                position: MAX_DEPTH * "GEOMETRYCOLLECTION(".len(),
+            })
+        );
+        let err = normalize_wkt(&"GEOMETRYCOLLECTION (".repeat(100_000)).unwrap_err();
+        assert_eq!(err.message, "geometries are nested too deeply");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn wkt_lines_are_normalized_one_by_one() {
+        use crate::Orientation;
+
+        let input = "POLYGON ((0 0, 5 0, 5 5, 0 5, 0 0))\r\n  # comment (1 1)\n\t\nPOINT (1 2)\nPOLYGON ((0 0,\nLINESTRING (0 0, 1 1)";
+        let mut output = Vec::new();
+        let errors = normalize_wkt_lines(input.as_bytes(), &mut output, true).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "POLYGON((0 0,0 5,5 5,5 0,0 0))\r\n  # comment (1 1)\n\t\nPOINT(1 2)\nPOLYGON ((0 0,\nLINESTRING(0 0,1 1)"
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 5);
+
+        let mut output = Vec::new();
+        let err = normalize_wkt_lines(input.as_bytes(), &mut output, false).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.into_inner().unwrap().is::<WktError>());
+        assert!(String::from_utf8(output).unwrap().ends_with("POINT(1 2)\n"));
+
+        let opts = NormalizeOptions {
+            orientation: Orientation::GeoJson,
+            ..Default::default()
+        };
+        let mut output = Vec::new();
+        normalize_wkt_lines_with(
+            &b"POLYGON ((0 0, 0 5, 5 5, 5 0, 0 0))"[..],
+            &mut output,
+            opts,
+            false,
+        )
+        .unwrap();
+        assert_eq!(output, b"POLYGON((0 0,5 0,5 5,0 5,0 0))");
+    }
+}